@@ -0,0 +1,34 @@
+#![allow(dead_code)]
+#![allow(unused_imports)]
+
+use std::{fs::File, io::BufReader};
+
+use quickexif::{BlockKind, TagQuery};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let sample = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "examples/samples/sample0.CR3".to_owned());
+    let mut reader = BufReader::new(File::open(sample)?);
+
+    let (format, blocks) = quickexif::open_any(&mut reader)?;
+    println!("{:?}", format);
+
+    for block in blocks {
+        println!("{:?} at {:#x}", block.kind, block.offset);
+        if block.kind != BlockKind::Tiff {
+            continue;
+        }
+
+        let mut query = TagQuery::new();
+        query.add("0", 0x010f, "make")?;
+        query.add("0", 0x0110, "model")?;
+
+        block.seek(&mut reader)?;
+        let result = query.parse(BufReader::new(&mut reader))?;
+        println!("{:?}", result.get("make").and_then(|x| x.str()));
+        println!("{:?}", result.get("model").and_then(|x| x.str()));
+    }
+
+    Ok(())
+}
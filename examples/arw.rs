@@ -8,8 +8,7 @@ mod sony_tags {
     use quickexif::gen_tags_info;
 
     gen_tags_info!(
-        0 -> 0xc634 -> 0 {}
-        0 -> 0xc634 -> 0 -> 0x7200 -> 0xffff {
+        0 -> 0xc634 -> 0 -> 0x7200 -> 0 {
             0x7310 black_level
             0x7312 white_balance
             0x787f white_level
@@ -43,7 +42,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let sample = "examples/samples/sample1.ARW";
     let reader = BufReader::new(File::open(sample)?);
 
-    let (result, _) = quickexif::parse_exif(reader, sony_tags::PATH_LST, Some((0, 1)))?;
+    let (result, _) = quickexif::parse_exif(reader, sony_tags::PATH_LST)?;
 
     println!("{:?}", result.get(sony_tags::make).and_then(|x| x.str()));
     println!("{:?}", result.get(sony_tags::model).and_then(|x| x.str()));
@@ -0,0 +1,29 @@
+use std::{fs::File, io::BufReader};
+
+use quickexif::makernote::canon::CanonMakerNote;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let sample = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "examples/samples/sample0.CR3".to_owned());
+    let mut reader = BufReader::new(File::open(sample)?);
+
+    let canon = CanonMakerNote::read(&mut reader)?;
+    println!("{:#?}", canon.camera_settings);
+    println!("{:#?}", canon.shot_info);
+    println!("iso: {:?}", canon.shot_info.as_ref().and_then(|x| x.iso()));
+    println!("lens: {:?} {:?}", canon.lens_type(), canon.lens_model);
+    if let Some(x) = &canon.color_data {
+        println!("ColorData{} as shot: {:?}", x.version, x.as_shot);
+        println!("multipliers: {:?}", x.wb_multipliers());
+        println!("black: {:?} white: {:?}", x.black_levels, x.white_level);
+        for preset in x.presets.iter() {
+            println!("  {:?}", preset);
+        }
+    }
+    println!("{:?}", canon.af_info);
+    println!("{:?}", canon.file_info);
+    println!("{:?}", canon.sensor_info);
+
+    Ok(())
+}
@@ -3,6 +3,8 @@
 
 use std::{collections::HashMap, fs::File, io::BufReader};
 
+use quickexif::makernote::canon::ColorData;
+
 mod cr2_tags {
     #![allow(non_upper_case_globals)]
     use quickexif::gen_tags_info;
@@ -34,14 +36,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let sample = "examples/samples/sample0.CR2";
     let reader = BufReader::new(File::open(sample)?);
 
-    let (result, _) = quickexif::parse_exif(reader, cr2_tags::PATH_LST, None)?;
+    let (result, _) = quickexif::parse_exif(reader, cr2_tags::PATH_LST)?;
 
     println!("{:?}", result.get(cr2_tags::make).and_then(|x| x.str()));
     println!("{:?}", result.get(cr2_tags::model).and_then(|x| x.str()));
     println!("{:?}", result.get(cr2_tags::orientation).map(|x| x.u16()));
     println!("{:?}", result.get(cr2_tags::thumbnail).map(|x| x.u32()));
     println!("{:?}", result.get(cr2_tags::thumbnail_length).map(|x| x.u32()));
-    println!("{:x?}", result.get(cr2_tags::colordata).map(|x| x.raw()));
+    let color_data = result.get(cr2_tags::colordata).and_then(ColorData::decode);
+    println!("{:?}", color_data.as_ref().map(|x| x.as_shot));
+    println!("{:?}", color_data.as_ref().and_then(|x| x.wb_multipliers()));
     println!("{:x?}", result.get(cr2_tags::strip).map(|x| x.u32()));
     println!("{:x?}", result.get(cr2_tags::strip_count).map(|x| x.u32()));
     println!("{:x?}", result.get(cr2_tags::cfa_pattern).map(|x| x.raw()));
@@ -57,7 +57,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         let mut reader = BufReader::new(File::open(sample)?);
         quickexif::seek_header_cr3(&mut reader, 0)?;
         
-        let (result, _) = quickexif::parse_exif(reader, cr3_tags1::PATH_LST, None)?;
+        let (result, _) = quickexif::parse_exif(reader, cr3_tags1::PATH_LST)?;
     
         println!("{:?}", result.get(cr3_tags1::make).and_then(|x| x.str()));
         println!("{:?}", result.get(cr3_tags1::model).and_then(|x| x.str()));
@@ -67,7 +67,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         let mut reader = BufReader::new(File::open(sample)?);
         quickexif::seek_header_cr3(&mut reader, 1)?;
 
-        let (result, _) = quickexif::parse_exif(reader, cr3_tags2::PATH_LST, None)?;
+        let (result, _) = quickexif::parse_exif(reader, cr3_tags2::PATH_LST)?;
 
         println!("{:?}", result.get(cr3_tags2::width).map(|x| x.u32()));
         println!("{:?}", result.get(cr3_tags2::height).map(|x| x.u32()));
@@ -76,7 +76,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         let mut reader = BufReader::new(File::open(sample)?);
         quickexif::seek_header_cr3(&mut reader, 2)?;
 
-        let (result, _) = quickexif::parse_exif(reader, cr3_tags3::PATH_LST, None)?;
+        let (result, _) = quickexif::parse_exif(reader, cr3_tags3::PATH_LST)?;
 
         println!("{:?}", result.get(cr3_tags3::battery).and_then(|x| x.str()));
     }
@@ -84,7 +84,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         let mut reader = BufReader::new(File::open(sample)?);
         quickexif::seek_header_cr3(&mut reader, 4)?;
 
-        let (result, _) = quickexif::parse_exif(reader, cr3_tags4::PATH_LST, None)?;
+        let (result, _) = quickexif::parse_exif(reader, cr3_tags4::PATH_LST)?;
 
         println!("{:?}", result.get(cr3_tags4::colordata).map(|x| x.raw()));
     }
@@ -93,7 +93,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let sample = "examples/samples/sample0.dng";
     let reader = BufReader::new(File::open(sample)?);
 
-    let (result, _) = quickexif::parse_exif(reader, adobe_tags::PATH_LST, None)?;
+    let (result, _) = quickexif::parse_exif(reader, adobe_tags::PATH_LST)?;
 
     println!("{:?}", result.get(adobe_tags::make).and_then(|x| x.str()));
     println!("{:?}", result.get(adobe_tags::model).and_then(|x| x.str()));
@@ -103,7 +103,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("{:?}", result.get(adobe_tags::color_matrix_2).and_then(|x| x.r64s()));
     println!("{:x?}", result.get(adobe_tags::color_matrix_2).map(|x| x.addr()));
 
-    if result.get(adobe_tags::cfa_pattern0).is_some() {
+    if result.contains_key(adobe_tags::cfa_pattern0) {
         println!("{:?}", result.get(adobe_tags::thumbnail0).map(|x| x.u32()));
         println!("{:?}", result.get(adobe_tags::thumbnail_len0).map(|x| x.u32()));
 
@@ -122,7 +122,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         println!("{:?}", result.get(adobe_tags::crop_size0).and_then(|x| x.r64s()));
     }
 
-    if result.get(adobe_tags::cfa_pattern1).is_some() {
+    if result.contains_key(adobe_tags::cfa_pattern1) {
         println!("{:?}", result.get(adobe_tags::thumbnail2).map(|x| x.u32()));
         println!("{:?}", result.get(adobe_tags::thumbnail_len2).map(|x| x.u32()));
 
@@ -0,0 +1,54 @@
+#![allow(dead_code)]
+#![allow(unused_imports)]
+
+use std::{fs::File, io::BufReader};
+
+use quickexif::{
+    tags::{IfdContext, Vendor},
+    IFDNode,
+};
+
+fn print_node(node: &IFDNode, vendor: Option<Vendor>) {
+    println!("[{}]", quickexif::format_tag_path(&node.path));
+    let context = IfdContext::from_path(&node.path, vendor);
+    for item in node.entries.iter() {
+        let raw = item.raw();
+        let name = match context {
+            Some(context) => context.key(item.tag()),
+            None => format!("{:#06x}", item.tag()),
+        };
+        println!(
+            "  {:32} format: {:2} size: {:6} {:x?}",
+            name,
+            item.format(),
+            item.size64(),
+            &raw[..raw.len().min(16)]
+        );
+        if let Some(x) = context.and_then(|x| x.interpret(item)) {
+            println!("  {:32} => {}", "", x);
+        }
+    }
+    for child in node.children.iter() {
+        print_node(child, vendor);
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let sample = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "examples/samples/sample0.JPG".to_owned());
+    let reader = BufReader::new(File::open(sample)?);
+
+    let nodes = quickexif::parse_exif_tree(reader)?;
+    let vendor = nodes
+        .iter()
+        .find(|x| x.path == [0])
+        .and_then(|x| x.entries.iter().find(|x| x.tag() == 0x010f))
+        .and_then(|x| x.str())
+        .and_then(Vendor::from_make);
+    for node in nodes.iter() {
+        print_node(node, vendor);
+    }
+
+    Ok(())
+}
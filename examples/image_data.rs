@@ -0,0 +1,39 @@
+use std::{
+    fs::File,
+    io::{BufReader, Seek, SeekFrom},
+};
+
+use quickexif::{image_data::ImageData, IFDNode};
+
+fn print_image_data(node: &IFDNode, stream_len: u64) {
+    if node.get(0x0111).is_some() || node.get(0x0144).is_some() {
+        println!("[{}]", quickexif::format_tag_path(&node.path));
+        match ImageData::from_node(node, 0, stream_len) {
+            Ok(data) => {
+                println!("  {}x{} {:?} planes: {}", data.width, data.height, data.layout, data.planes);
+                for x in data.iter().take(8) {
+                    println!("  {:?}", x);
+                }
+            }
+            Err(e) => println!("  {}", e),
+        }
+    }
+    for child in node.children.iter() {
+        print_image_data(child, stream_len);
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let sample = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "examples/samples/sample0.dng".to_owned());
+    let mut reader = BufReader::new(File::open(sample)?);
+    let stream_len = reader.seek(SeekFrom::End(0))?;
+    reader.rewind()?;
+
+    for node in quickexif::parse_exif_tree(reader)? {
+        print_image_data(&node, stream_len);
+    }
+
+    Ok(())
+}
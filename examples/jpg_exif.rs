@@ -32,13 +32,19 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let sample = "examples/samples/sample0.JPG";
     let reader = BufReader::new(File::open(sample)?);
 
-    let (result, _) = quickexif::parse_exif(reader, jpg_tags::PATH_LST, None)?;
+    let (result, _) = quickexif::parse_exif(reader, jpg_tags::PATH_LST)?;
 
     println!("{:?}", result.get(jpg_tags::make).and_then(|x| x.str()));
     println!("{:?}", result.get(jpg_tags::model).and_then(|x| x.str()));
 
     println!("{:?}", result.get(jpg_tags::iso).map(|x| x.u16()));
-    println!("{:?}", result.get(jpg_tags::exposure_time).and_then(|x| x.r64s()));
+    println!(
+        "{:?}",
+        result
+            .get(jpg_tags::exposure_time)
+            .and_then(|x| x.rational())
+            .map(|x| x.to_string())
+    );
     println!("{:?}", result.get(jpg_tags::f_number).and_then(|x| x.r64s()));
     println!("{:?}", result.get(jpg_tags::create_date).and_then(|x| x.str()));
     println!("{:?}", result.get(jpg_tags::focal_length).and_then(|x| x.r64s()));
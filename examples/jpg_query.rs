@@ -0,0 +1,29 @@
+#![allow(dead_code)]
+#![allow(unused_imports)]
+
+use std::{fs::File, io::BufReader};
+
+use quickexif::TagQuery;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let sample = "examples/samples/sample0.JPG";
+    let reader = BufReader::new(File::open(sample)?);
+
+    // the same lookups as jpg_exif.rs, but declared at runtime
+    let mut query = TagQuery::new();
+    query.add("0", 0x010f, "make")?;
+    query.add("0", 0x0110, "model")?;
+    query.add("0/0x8769/0", 0x829d, "f_number")?;
+    query.add("0/0x8769/0", 0x9004, "create_date")?;
+    query.add("1", 0x0201, "thumb_addr")?;
+
+    let result = query.parse(reader)?;
+
+    println!("{:?}", result.get("make").and_then(|x| x.str()));
+    println!("{:?}", result.get("model").and_then(|x| x.str()));
+    println!("{:?}", result.get("f_number").and_then(|x| x.r64s()));
+    println!("{:?}", result.get("create_date").and_then(|x| x.str()));
+    println!("{:?}", result.get("thumb_addr").map(|x| x.u32()));
+
+    Ok(())
+}
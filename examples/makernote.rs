@@ -0,0 +1,35 @@
+use std::{fs::File, io::BufReader};
+
+use quickexif::{
+    makernote::{self, Layout, MakerNoteParser},
+    TagQuery,
+};
+
+/// A made up vendor with a 4 bytes signature before a plain IFD
+struct Acme;
+
+impl MakerNoteParser for Acme {
+    fn matches(&self, make: Option<&str>, header: &[u8]) -> bool {
+        make == Some("ACME") && header.starts_with(b"ACME")
+    }
+    fn layout(&self, _: &[u8], _: bool) -> Layout {
+        Layout::after_signature(4)
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let sample = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "examples/samples/sample0.JPG".to_owned());
+    makernote::register(&Acme);
+
+    let mut query = TagQuery::new();
+    query.add("0", 0x010f, "make")?;
+    query.add("0/0x8769/0/0x927c/0", 0x0001, "first")?;
+    let result = query.parse(BufReader::new(File::open(sample)?))?;
+
+    println!("{:?}", result.get("make").and_then(|x| x.str()));
+    println!("{:?}", result.get("first").map(|x| x.value()));
+
+    Ok(())
+}
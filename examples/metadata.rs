@@ -0,0 +1,24 @@
+use std::{fs::File, io::BufReader};
+
+use quickexif::Metadata;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let sample = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "examples/samples/sample0.JPG".to_owned());
+    let mut reader = BufReader::new(File::open(sample)?);
+
+    let metadata = Metadata::read(&mut reader)?;
+    println!("{:#?}", metadata);
+    if let Some(x) = metadata.orientation {
+        println!("orientation: {}", x);
+    }
+    if let Some(x) = metadata.capture_time {
+        println!("capture time: {} ({:?})", x, x.timestamp());
+    }
+    if let Some(x) = metadata.flash {
+        println!("flash: {}", x);
+    }
+
+    Ok(())
+}
@@ -3,6 +3,8 @@
 
 use std::{collections::HashMap, fs::File, io::BufReader};
 
+use quickexif::makernote::nikon::NikonMakerNote;
+
 mod nikon_tags {
     #![allow(non_upper_case_globals)]
     use quickexif::gen_tags_info;
@@ -40,7 +42,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let sample = "examples/samples/sample0.NEF";
     let reader = BufReader::new(File::open(sample)?);
 
-    let (result, _) = quickexif::parse_exif(reader, nikon_tags::PATH_LST, None)?;
+    let (result, _) = quickexif::parse_exif(reader, nikon_tags::PATH_LST)?;
 
     println!("{:?}", result.get(nikon_tags::orientation).map(|x| x.u16()));
     println!("{:?}", result.get(nikon_tags::thumbnail).map(|x| x.u32()));
@@ -58,5 +60,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("{:?}", result.get(nikon_tags::strip).map(|x| x.u32()));
     println!("{:?}", result.get(nikon_tags::strip_len).map(|x| x.u32()));
 
+    // the encrypted records
+    let mut reader = BufReader::new(File::open(sample)?);
+    let makernote = NikonMakerNote::read(&mut reader)?;
+    println!("{:?}", makernote.color_balance);
+    println!("{:?}", makernote.lens_data);
+
     Ok(())
 }
@@ -0,0 +1,29 @@
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter},
+};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut args = std::env::args().skip(1);
+    let sample = args
+        .next()
+        .unwrap_or_else(|| "examples/samples/sample0.dng".to_owned());
+    let mut reader = BufReader::new(File::open(sample)?);
+
+    for x in quickexif::previews(&mut reader)? {
+        println!(
+            "{:?} {}x{} at {:#x} ({} bytes) from {:?}",
+            x.kind, x.width, x.height, x.data.offset, x.data.length, x.source
+        );
+    }
+
+    // copies the largest one when an output path is given
+    if let Some(output) = args.next() {
+        let mut writer = BufWriter::new(File::create(output)?);
+        if let Some(x) = quickexif::copy_largest_preview(&mut reader, &mut writer)? {
+            println!("copied {}x{}", x.width, x.height);
+        }
+    }
+
+    Ok(())
+}
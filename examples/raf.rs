@@ -43,15 +43,23 @@ mod fuji_tags2 {
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let sample = "examples/samples/sample0.RAF";
+    {
+        let mut reader = BufReader::new(File::open(sample)?);
+        let raf = quickexif::raf::Raf::parse(&mut reader)?;
+
+        println!("{:?} {:?}", raf.camera, raf.format_version);
+        println!("{:x?}", (raf.jpeg_offset, raf.jpeg_length));
+        println!("{:x?}", (raf.cfa_offset, raf.cfa_length));
+        println!("{:?}", raf.raw_full_size());
+        println!("{:?}", raf.cropped_size());
+        println!("{:?}", raf.xtrans_layout());
+        println!("{:?}", raf.wb_grgb());
+    }
     {
         let mut reader = BufReader::new(File::open(sample)?);
         quickexif::seek_header_raf(&mut reader, 0)?;
 
-        let (result, _) = quickexif::parse_exif(
-            reader,
-            fuji_tags1::PATH_LST,
-            None
-        )?;
+        let (result, _) = quickexif::parse_exif(reader, fuji_tags1::PATH_LST)?;
         println!("{:?}", result.get(fuji_tags1::orientation).map(|x| x.u16()));
         println!("{:?}", result.get(fuji_tags1::make).and_then(|x| x.str()));
         println!("{:?}", result.get(fuji_tags1::model).and_then(|x| x.str()));
@@ -66,7 +74,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         let mut reader = BufReader::new(File::open(sample)?);
         quickexif::seek_header_raf(&mut reader, 1)?;
 
-        let (result, _) = quickexif::parse_exif(reader, fuji_tags2::PATH_LST, None)?;
+        let (result, _) = quickexif::parse_exif(reader, fuji_tags2::PATH_LST)?;
 
         println!("{:?}", result.get(fuji_tags2::width).map(|x| x.u32()));
         println!("{:?}", result.get(fuji_tags2::height).map(|x| x.u32()));
@@ -0,0 +1,478 @@
+use std::io::{BufReader, Read, Seek, SeekFrom};
+
+use crate::ToReport;
+use erreport::Report;
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("Invalid box {0:?} with size {1}")]
+    InvalidBox(String, u64),
+    #[error("Not a CR3 file, the major brand is {0:?}")]
+    InvalidBrand(String),
+}
+
+/// The uuid box holding Canon's CNCV, CCTP, CTBO, CMT1-4 and THMB boxes
+pub const CANON_UUID: [u8; 16] = [
+    0x85, 0xc0, 0xb6, 0x87, 0x82, 0x0f, 0x11, 0xe0, 0x81, 0x11, 0xf4, 0xce, 0x46, 0x2b, 0x6a, 0x48,
+];
+/// The uuid box holding the PRVW box
+pub const PREVIEW_UUID: [u8; 16] = [
+    0xea, 0xf4, 0x2b, 0x5e, 0x1c, 0x98, 0x4b, 0x88, 0xb9, 0xfb, 0xb7, 0xdc, 0x40, 0x6e, 0x4d, 0x16,
+];
+pub const XMP_UUID: [u8; 16] = [
+    0xbe, 0x7a, 0xcf, 0xcb, 0x97, 0xa9, 0x42, 0xe8, 0x9c, 0x71, 0x99, 0x94, 0x91, 0xe3, 0xaf, 0xac,
+];
+
+/// A byte range in the file
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Range {
+    pub offset: u64,
+    pub length: u64,
+}
+
+/// An embedded JPEG from the THMB or PRVW box
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Image {
+    pub width: u16,
+    pub height: u16,
+    pub data: Range,
+}
+
+/// The CRX codec parameters from the CMP1 box of a CRAW track
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CrawParams {
+    pub version: u16,
+    pub width: u32,
+    pub height: u32,
+    pub tile_width: u32,
+    pub tile_height: u32,
+    pub bits: u8,
+    pub planes: u8,
+    pub cfa_layout: u8,
+    pub enc_type: u8,
+    pub image_levels: u8,
+    pub has_tile_cols: bool,
+    pub has_tile_rows: bool,
+    pub mdat_header_size: u32,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Track {
+    /// The sample entry type in stsd, `CRAW` for the image tracks and `CTMD` for timed metadata
+    pub codec: [u8; 4],
+    pub width: u16,
+    pub height: u16,
+    /// One range per chunk, CR3 stores a single sample in every chunk
+    pub samples: Vec<Range>,
+    /// The size shared by every sample from stsz, 0 when each sample has its own
+    pub sample_size: u32,
+    /// Only present for CRX encoded tracks, the JPEG track has none
+    pub craw: Option<CrawParams>,
+}
+
+/// A TIFF block inside a CTMD record
+#[derive(Debug, Clone, Copy)]
+pub struct CtmdBlock {
+    pub record_type: u16,
+    /// The tag the block stands for, 0x8769 for ExifIFD or 0x927c for the makernote
+    pub tag: u32,
+    /// Where the TIFF header starts
+    pub offset: u64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Cr3 {
+    pub compressor_version: Option<String>,
+    /// TIFF blocks of CMT1 (IFD0), CMT2 (ExifIFD), CMT3 (makernote) and CMT4 (GPS)
+    pub cmt: [Option<Range>; 4],
+    /// (index, range) entries of the CTBO table
+    pub ctbo: Vec<(u32, Range)>,
+    pub thumbnail: Option<Image>,
+    pub preview: Option<Image>,
+    pub xmp: Option<Range>,
+    pub tracks: Vec<Track>,
+    pub ctmd: Vec<CtmdBlock>,
+}
+
+struct BoxHeader {
+    name: [u8; 4],
+    uuid: Option<[u8; 16]>,
+    content: u64,
+    end: u64,
+}
+
+trait ReadBE {
+    fn be_u16(&mut self) -> Result<u16, Report>;
+    fn be_u32(&mut self) -> Result<u32, Report>;
+    fn be_u64(&mut self) -> Result<u64, Report>;
+}
+
+macro_rules! gen_read_be {
+    ($name:ident, $t:ident) => {
+        fn $name(&mut self) -> Result<$t, Report> {
+            let mut x = [0u8; std::mem::size_of::<$t>()];
+            self.read_exact(&mut x).to_report()?;
+            Ok($t::from_be_bytes(x))
+        }
+    };
+}
+
+impl<T: Read> ReadBE for T {
+    gen_read_be!(be_u16, u16);
+    gen_read_be!(be_u32, u32);
+    gen_read_be!(be_u64, u64);
+}
+
+impl Cr3 {
+    /// Walks the box structure from the current position, which should be the `ftyp` box
+    pub fn parse<T: Read + Seek>(reader: &mut BufReader<T>) -> Result<Self, Report> {
+        let start = reader.stream_position().to_report()?;
+        let end = reader.seek(SeekFrom::End(0)).to_report()?;
+        reader.seek(SeekFrom::Start(start)).to_report()?;
+
+        let ftyp = read_box_header(reader, end).to_report()?;
+        let brand = reader.be_u32().to_report()?.to_be_bytes();
+        if &ftyp.name != b"ftyp" || &brand != b"crx " {
+            return Err(Error::InvalidBrand(
+                String::from_utf8_lossy(&brand).into_owned(),
+            ))
+            .to_report();
+        }
+        reader.seek(SeekFrom::Start(ftyp.end)).to_report()?;
+
+        let mut cr3 = Cr3::default();
+        cr3.walk(reader, ftyp.end, end).to_report()?;
+        cr3.parse_ctmd(reader).to_report()?;
+
+        Ok(cr3)
+    }
+
+    fn walk<T: Read + Seek>(
+        &mut self,
+        reader: &mut BufReader<T>,
+        start: u64,
+        end: u64,
+    ) -> Result<(), Report> {
+        let mut pos = start;
+        while pos + 8 <= end {
+            reader.seek(SeekFrom::Start(pos)).to_report()?;
+            let header = read_box_header(reader, end).to_report()?;
+            self.visit(reader, &header).to_report()?;
+            pos = header.end;
+        }
+        Ok(())
+    }
+
+    fn visit<T: Read + Seek>(
+        &mut self,
+        reader: &mut BufReader<T>,
+        header: &BoxHeader,
+    ) -> Result<(), Report> {
+        let content_len = header.end - header.content;
+        match &header.name {
+            b"moov" | b"mdia" | b"minf" | b"stbl" | b"dinf" => {
+                self.walk(reader, header.content, header.end).to_report()?;
+            }
+            b"trak" => {
+                self.tracks.push(Track::default());
+                self.walk(reader, header.content, header.end).to_report()?;
+            }
+            b"uuid" => match header.uuid {
+                Some(CANON_UUID) => {
+                    self.walk(reader, header.content, header.end).to_report()?;
+                }
+                Some(PREVIEW_UUID) => {
+                    // 8 unknown bytes before the PRVW box
+                    self.walk(reader, header.content + 8, header.end).to_report()?;
+                }
+                Some(XMP_UUID) => {
+                    self.xmp = Some(Range {
+                        offset: header.content,
+                        length: content_len,
+                    });
+                }
+                _ => {}
+            },
+            b"CNCV" => {
+                let mut x = vec![0u8; content_len as usize];
+                reader.read_exact(&mut x).to_report()?;
+                self.compressor_version = Some(
+                    String::from_utf8_lossy(&x)
+                        .trim_end_matches('\0')
+                        .to_owned(),
+                );
+            }
+            [b'C', b'M', b'T', n @ b'1'..=b'4'] => {
+                self.cmt[(n - b'1') as usize] = Some(Range {
+                    offset: header.content,
+                    length: content_len,
+                });
+            }
+            b"CTBO" => {
+                let count = reader.be_u32().to_report()?;
+                check_table(header, reader, count, 20).to_report()?;
+                for _ in 0..count {
+                    let index = reader.be_u32().to_report()?;
+                    let offset = reader.be_u64().to_report()?;
+                    let length = reader.be_u64().to_report()?;
+                    self.ctbo.push((index, Range { offset, length }));
+                }
+            }
+            b"THMB" => {
+                reader.seek_relative(4).to_report()?; // version and flags
+                self.thumbnail = Some(read_image(reader, 0, header.content + 16).to_report()?);
+            }
+            b"PRVW" => {
+                reader.seek_relative(6).to_report()?;
+                self.preview = Some(read_image(reader, 2, header.content + 16).to_report()?);
+            }
+            b"stsd" => {
+                // version, flags and entry count before the sample entries
+                self.walk(reader, header.content + 8, header.end).to_report()?;
+            }
+            b"CRAW" | b"CTMD" => {
+                if let Some(track) = self.tracks.last_mut() {
+                    track.codec = header.name;
+                    if &header.name == b"CRAW" {
+                        // sample entry fields before width and height
+                        reader.seek_relative(24).to_report()?;
+                        track.width = reader.be_u16().to_report()?;
+                        track.height = reader.be_u16().to_report()?;
+                    }
+                }
+                if &header.name == b"CRAW" {
+                    // the child boxes (CMP1, CDI1, JPEG) follow 82 bytes of sample entry fields,
+                    // a broken child only loses the codec parameters of this track
+                    let _ = self.walk(reader, header.content + 82, header.end);
+                }
+            }
+            b"CMP1" => {
+                let mut x = [0u8; 32];
+                reader.read_exact(&mut x).to_report()?;
+                let u32_at = |i: usize| u32::from_be_bytes([x[i], x[i + 1], x[i + 2], x[i + 3]]);
+                let params = CrawParams {
+                    version: u16::from_be_bytes([x[4], x[5]]),
+                    width: u32_at(8),
+                    height: u32_at(12),
+                    tile_width: u32_at(16),
+                    tile_height: u32_at(20),
+                    bits: x[24],
+                    planes: x[25] >> 4,
+                    cfa_layout: x[25] & 0x0f,
+                    enc_type: x[26] >> 4,
+                    image_levels: x[26] & 0x0f,
+                    has_tile_cols: x[27] >> 7 == 1,
+                    has_tile_rows: (x[27] >> 6) & 1 == 1,
+                    mdat_header_size: u32_at(28),
+                };
+                if let Some(track) = self.tracks.last_mut() {
+                    track.craw = Some(params);
+                }
+            }
+            b"stsz" => {
+                reader.seek_relative(4).to_report()?;
+                let sample_size = reader.be_u32().to_report()?;
+                let count = reader.be_u32().to_report()?;
+                if sample_size != 0 {
+                    // no table follows, the size is applied to the chunks of co64 or stco
+                    if let Some(track) = self.tracks.last_mut() {
+                        track.sample_size = sample_size;
+                        for sample in track.samples.iter_mut() {
+                            sample.length = sample_size as u64;
+                        }
+                    }
+                    return Ok(());
+                }
+                check_table(header, reader, count, 4).to_report()?;
+                let sizes = (0..count)
+                    .map(|_| reader.be_u32().map(|x| x as u64))
+                    .collect::<Result<Vec<_>, _>>()
+                    .to_report()?;
+                self.update_samples(sizes, |x, size| x.length = size);
+            }
+            b"co64" | b"stco" => {
+                reader.seek_relative(4).to_report()?;
+                let count = reader.be_u32().to_report()?;
+                let elem_size = if &header.name == b"co64" { 8 } else { 4 };
+                check_table(header, reader, count, elem_size).to_report()?;
+                let offsets = (0..count)
+                    .map(|_| match &header.name {
+                        b"co64" => reader.be_u64(),
+                        _ => reader.be_u32().map(|x| x as u64),
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+                    .to_report()?;
+                self.update_samples(offsets, |x, offset| x.offset = offset);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn update_samples(&mut self, values: Vec<u64>, f: impl Fn(&mut Range, u64)) {
+        if let Some(track) = self.tracks.last_mut() {
+            if track.samples.len() < values.len() {
+                let sample = Range {
+                    offset: 0,
+                    length: track.sample_size as u64,
+                };
+                track.samples.resize(values.len(), sample);
+            }
+            for (sample, value) in track.samples.iter_mut().zip(values) {
+                f(sample, value);
+            }
+        }
+    }
+
+    /// Finds the TIFF blocks in the records of the first CTMD sample.
+    ///
+    /// A record is (size: u32, type: u16, 6 unknown bytes, payload) in little endian,
+    /// and the payload of the type 7, 8 and 9 records is a list of (size: u32, tag: u32, TIFF).
+    fn parse_ctmd<T: Read + Seek>(&mut self, reader: &mut BufReader<T>) -> Result<(), Report> {
+        let sample = match self
+            .tracks
+            .iter()
+            .find(|x| &x.codec == b"CTMD")
+            .and_then(|x| x.samples.first())
+        {
+            Some(&x) => x,
+            None => return Ok(()),
+        };
+
+        let read_u32 = |reader: &mut BufReader<T>| -> Result<u32, Report> {
+            let mut x = [0u8; 4];
+            reader.read_exact(&mut x).to_report()?;
+            Ok(u32::from_le_bytes(x))
+        };
+
+        let end = sample.offset.saturating_add(sample.length);
+        let mut pos = sample.offset;
+        while pos + 12 <= end {
+            reader.seek(SeekFrom::Start(pos)).to_report()?;
+            let size = read_u32(reader).to_report()? as u64;
+            let record_type = {
+                let mut x = [0u8; 2];
+                reader.read_exact(&mut x).to_report()?;
+                u16::from_le_bytes(x)
+            };
+            if size < 12 {
+                return Err(Error::InvalidBox(format!("CTMD record {}", record_type), size))
+                    .to_report();
+            }
+
+            if let 7..=9 = record_type {
+                let record_end = pos + size;
+                let mut block_pos = pos + 12;
+                while block_pos + 8 <= record_end {
+                    reader.seek(SeekFrom::Start(block_pos)).to_report()?;
+                    let block_size = read_u32(reader).to_report()? as u64;
+                    let tag = read_u32(reader).to_report()?;
+                    if block_size < 8 {
+                        break;
+                    }
+                    self.ctmd.push(CtmdBlock {
+                        record_type,
+                        tag,
+                        offset: block_pos + 8,
+                    });
+                    block_pos += block_size;
+                }
+            }
+            pos += size;
+        }
+        Ok(())
+    }
+
+    /// The TIFF header offset of the blocks addressed by `seek_header_cr3`
+    pub fn part_offset(&self, part: u8) -> Option<u64> {
+        let makernotes = || self.ctmd.iter().filter(|x| x.tag == 0x927c);
+        match part {
+            0..=2 => self.cmt[part as usize].map(|x| x.offset),
+            3 => makernotes().next().map(|x| x.offset),
+            4 => makernotes().nth(1).map(|x| x.offset),
+            5 => self.cmt[3].map(|x| x.offset),
+            _ => None,
+        }
+    }
+}
+
+fn read_box_header<T: Read + Seek>(
+    reader: &mut BufReader<T>,
+    parent_end: u64,
+) -> Result<BoxHeader, Report> {
+    let start = reader.stream_position().to_report()?;
+    let size = reader.be_u32().to_report()? as u64;
+    let mut name = [0u8; 4];
+    reader.read_exact(&mut name).to_report()?;
+
+    let (size, mut header_len) = match size {
+        0 => (parent_end.saturating_sub(start), 8),
+        1 => (reader.be_u64().to_report()?, 16),
+        x => (x, 8),
+    };
+    let uuid = if &name == b"uuid" {
+        let mut x = [0u8; 16];
+        reader.read_exact(&mut x).to_report()?;
+        header_len += 16;
+        Some(x)
+    } else {
+        None
+    };
+
+    let end = match start.checked_add(size) {
+        Some(end) if size >= header_len && end <= parent_end => end,
+        _ => {
+            return Err(Error::InvalidBox(
+                String::from_utf8_lossy(&name).into_owned(),
+                size,
+            ))
+            .to_report();
+        }
+    };
+
+    Ok(BoxHeader {
+        name,
+        uuid,
+        content: start + header_len,
+        end,
+    })
+}
+
+/// Fails when a table of `count` entries of `elem_size` bytes runs past the box
+fn check_table<T: Read + Seek>(
+    header: &BoxHeader,
+    reader: &mut BufReader<T>,
+    count: u32,
+    elem_size: u64,
+) -> Result<(), Report> {
+    let pos = reader.stream_position().to_report()?;
+    if count as u64 > header.end.saturating_sub(pos) / elem_size {
+        return Err(Error::InvalidBox(
+            String::from_utf8_lossy(&header.name).into_owned(),
+            header.end - header.content,
+        ))
+        .to_report();
+    }
+    Ok(())
+}
+
+/// Reads (width: u16, height: u16, `gap` bytes, jpeg size: u32),
+/// the JPEG itself starts from `data_offset`
+fn read_image<T: Read + Seek>(
+    reader: &mut BufReader<T>,
+    gap: i64,
+    data_offset: u64,
+) -> Result<Image, Report> {
+    let width = reader.be_u16().to_report()?;
+    let height = reader.be_u16().to_report()?;
+    reader.seek_relative(gap).to_report()?;
+    let length = reader.be_u32().to_report()? as u64;
+    Ok(Image {
+        width,
+        height,
+        data: Range {
+            offset: data_offset,
+            length,
+        },
+    })
+}
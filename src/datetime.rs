@@ -0,0 +1,288 @@
+use std::fmt;
+
+use crate::Rational;
+
+/// An EXIF date and time, like DateTimeOriginal merged with its OffsetTime and SubSecTime
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DateTime {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+    pub nanosecond: u32,
+    /// Minutes east of UTC, `None` when the zone is unknown
+    pub offset: Option<i16>,
+}
+
+/// A byte cursor for the lenient parsers
+struct Cursor<'a> {
+    s: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(s: &'a str) -> Self {
+        Self {
+            s: s.as_bytes(),
+            pos: 0,
+        }
+    }
+    fn peek(&self) -> Option<u8> {
+        self.s.get(self.pos).copied()
+    }
+    fn is_end(&self) -> bool {
+        self.pos >= self.s.len()
+    }
+    /// Skips the bytes matching `f`, returns how many are skipped
+    fn skip(&mut self, f: impl Fn(u8) -> bool) -> usize {
+        let start = self.pos;
+        while self.peek().is_some_and(&f) {
+            self.pos += 1;
+        }
+        self.pos - start
+    }
+    /// Reads 1 to `max_len` digits
+    fn number(&mut self, max_len: usize) -> Option<u32> {
+        let start = self.pos;
+        while self.pos - start < max_len && self.peek().is_some_and(|x| x.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        std::str::from_utf8(&self.s[start..self.pos])
+            .ok()?
+            .parse()
+            .ok()
+    }
+    /// Reads a date like `2023:05:01`, dashes and slashes are accepted as well
+    fn date(&mut self) -> Option<(u16, u8, u8)> {
+        let is_sep = |x| matches!(x, b':' | b'-' | b'/');
+        let year = self.number(4)?;
+        (self.skip(is_sep) == 1).then_some(())?;
+        let month = self.number(2)?;
+        (self.skip(is_sep) == 1).then_some(())?;
+        let day = self.number(2)?;
+
+        let valid = (1..=12).contains(&month) && (1..=days_in_month(year, month)).contains(&day);
+        valid.then_some((year as u16, month as u8, day as u8))
+    }
+    /// Reads fractions of seconds as nanoseconds, extra digits are dropped
+    fn fraction(&mut self) -> u32 {
+        let start = self.pos;
+        self.skip(|x| x.is_ascii_digit());
+        self.s[start..self.pos]
+            .iter()
+            .chain(std::iter::repeat(&b'0'))
+            .take(9)
+            .fold(0, |acc, x| acc * 10 + (x - b'0') as u32)
+    }
+    /// Reads `Z`, `+09:00`, `+0900` or `+09`
+    fn offset(&mut self) -> Option<i16> {
+        let sign = match self.peek()? {
+            b'Z' | b'z' => {
+                self.pos += 1;
+                return Some(0);
+            }
+            b'+' => 1,
+            b'-' => -1,
+            _ => return None,
+        };
+        self.pos += 1;
+        let start = self.pos;
+        let hours = self.number(2)?;
+        if self.pos - start != 2 {
+            return None;
+        }
+        self.skip(|x| x == b':');
+        let minutes = if self.is_end() { 0 } else { self.number(2)? };
+        (hours <= 14 && minutes < 60).then(|| sign * (hours * 60 + minutes) as i16)
+    }
+}
+
+/// Spaces and NULs pad many EXIF strings
+fn trim(s: &str) -> &str {
+    s.trim_matches(|x: char| x == '\0' || x.is_whitespace())
+}
+
+fn is_leap(year: u32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        2 if is_leap(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days since 1970-01-01, from Howard Hinnant's `days_from_civil`
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+impl DateTime {
+    /// Parses the EXIF form `2023:05:01 12:34:56`.
+    ///
+    /// Trailing spaces and NULs, dashes in the date, a `T` separator, missing seconds,
+    /// fractions and a trailing zone like `+09:00` are tolerated. Blank or zeroed values,
+    /// like `0000:00:00 00:00:00`, give `None`.
+    pub fn parse(s: &str) -> Option<Self> {
+        let mut cursor = Cursor::new(trim(s));
+        let (year, month, day) = cursor.date()?;
+
+        let mut time = [0u32; 3];
+        let mut nanosecond = 0;
+        if cursor.skip(|x| x == b' ' || x == b'T') > 0 {
+            for (i, x) in time.iter_mut().enumerate() {
+                if i > 0 && cursor.skip(|x| x == b':') != 1 {
+                    // seconds are optional
+                    if i == 2 {
+                        break;
+                    }
+                    return None;
+                }
+                *x = cursor.number(2)?;
+            }
+            if cursor.skip(|x| x == b'.' || x == b',') == 1 {
+                nanosecond = cursor.fraction();
+            }
+        }
+        let [hour, minute, second] = time;
+        if hour > 23 || minute > 59 || second > 60 {
+            return None;
+        }
+
+        cursor.skip(|x| x == b' ');
+        let offset = cursor.offset();
+        if !cursor.is_end() {
+            return None;
+        }
+
+        Some(Self {
+            year,
+            month,
+            day,
+            hour: hour as u8,
+            minute: minute as u8,
+            second: second as u8,
+            nanosecond,
+            offset,
+        })
+    }
+
+    /// Builds a UTC time from GPSDateStamp and the hours, minutes and seconds of
+    /// GPSTimeStamp
+    pub fn from_gps(date_stamp: &str, time_stamp: &[Rational]) -> Option<Self> {
+        let mut cursor = Cursor::new(trim(date_stamp));
+        let (year, month, day) = cursor.date()?;
+        if !cursor.is_end() {
+            return None;
+        }
+
+        let [h, m, s] = [time_stamp.first()?, time_stamp.get(1)?, time_stamp.get(2)?]
+            .map(|x| x.to_f64());
+        let (h, m, s) = (h?, m?, s?);
+        if !(0.0..24.0).contains(&h) || !(0.0..60.0).contains(&m) || !(0.0..61.0).contains(&s) {
+            return None;
+        }
+
+        Some(Self {
+            year,
+            month,
+            day,
+            hour: h as u8,
+            minute: m as u8,
+            second: s as u8,
+            nanosecond: (s.fract() * 1e9).round().min(999_999_999.0) as u32,
+            offset: Some(0),
+        })
+    }
+
+    /// Merges an OffsetTime tag like `+09:00`, blank values are ignored
+    pub fn with_offset(mut self, offset: &str) -> Self {
+        let mut cursor = Cursor::new(trim(offset));
+        if let Some(x) = cursor.offset().filter(|_| cursor.is_end()) {
+            self.offset = Some(x);
+        }
+        self
+    }
+
+    /// Merges a SubSecTime tag, where `"5"` means 0.5 and `"005"` means 0.005 seconds
+    pub fn with_sub_sec(mut self, sub_sec: &str) -> Self {
+        let mut cursor = Cursor::new(trim(sub_sec));
+        if cursor.peek().is_some_and(|x| x.is_ascii_digit()) {
+            self.nanosecond = cursor.fraction();
+        }
+        self
+    }
+
+    /// Takes the zone from a UTC time of the same moment, like the GPS time stamp.
+    ///
+    /// The result is a guess: the difference is rounded to 15 minutes, and is only
+    /// used when it is within 3 minutes of that, so a fix taken a few minutes off still
+    /// gives a zone. A stale fix may still give a wrong one. A known offset is kept.
+    pub fn with_utc_reference(mut self, utc: &DateTime) -> Self {
+        if self.offset.is_some() {
+            return self;
+        }
+        let Some(utc) = utc.timestamp() else {
+            return self;
+        };
+        let diff = self.local_seconds() - utc;
+        let offset = (diff as f64 / 900.0).round() as i64 * 15;
+        if offset.abs() <= 14 * 60 && (diff - offset * 60).abs() <= 3 * 60 {
+            self.offset = Some(offset as i16);
+        }
+        self
+    }
+
+    /// Seconds since 1970-01-01 of the wall clock, ignoring the offset
+    fn local_seconds(&self) -> i64 {
+        let days = days_from_civil(self.year as i64, self.month as i64, self.day as i64);
+        days * 86400 + self.hour as i64 * 3600 + self.minute as i64 * 60 + self.second as i64
+    }
+
+    /// Unix time in seconds, `None` when the offset is unknown
+    pub fn timestamp(&self) -> Option<i64> {
+        Some(self.local_seconds() - self.offset? as i64 * 60)
+    }
+
+    /// Unix time in nanoseconds, for sorting photos from different zones
+    pub fn timestamp_nanos(&self) -> Option<i128> {
+        Some(self.timestamp()? as i128 * 1_000_000_000 + self.nanosecond as i128)
+    }
+}
+
+/// RFC 3339, like `2023-05-01T12:34:56.123+09:00`, without a zone when it is unknown
+impl fmt::Display for DateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )?;
+        if self.nanosecond != 0 {
+            let fraction = format!("{:09}", self.nanosecond);
+            write!(f, ".{}", fraction.trim_end_matches('0'))?;
+        }
+        match self.offset {
+            Some(0) => f.write_str("Z"),
+            Some(x) => write!(
+                f,
+                "{}{:02}:{:02}",
+                if x < 0 { '-' } else { '+' },
+                x.abs() / 60,
+                x.abs() % 60
+            ),
+            None => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,275 @@
+use std::io::{BufReader, Read, Seek, SeekFrom};
+
+use crate::{
+    cr3::Cr3, format_tag_path, jpeg, seek_header_raf, DataType, Error, Limits, ToReport,
+};
+use erreport::Report;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Jpeg,
+    Tiff,
+    Dng,
+    Cr2,
+    Cr3,
+    Raf,
+    Arw,
+    Nef,
+    Orf,
+    Rw2,
+    Pef,
+    Srw,
+}
+
+/// What the IFD0 of an EXIF block is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlockKind {
+    /// A regular TIFF structure starting from IFD0
+    Tiff,
+    /// A standalone ExifIFD, like CR3's CMT2
+    ExifIfd,
+    /// A standalone makernote IFD, like CR3's CMT3
+    MakerNote,
+    /// A standalone GPS IFD, like CR3's CMT4
+    GpsIfd,
+    /// The raw data IFDs, like the one in RAF's CFA section
+    RawIfd,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExifBlock {
+    pub kind: BlockKind,
+    /// Where `parse_exif` or `TagQuery::parse` should start reading
+    pub offset: u64,
+}
+
+impl ExifBlock {
+    pub fn seek<T: Read + Seek>(&self, reader: &mut BufReader<T>) -> Result<(), Report> {
+        reader.seek(SeekFrom::Start(self.offset)).to_report()?;
+        Ok(())
+    }
+}
+
+/// Detects the file format from the magic bytes, and from the Make and DNGVersion
+/// tags for the formats sharing the plain TIFF header. Other files, like PNG or HEIC,
+/// fail with `Error::UnknownFormat`.
+///
+/// The reader is restored to its current position afterwards.
+pub fn detect_format<T: Read + Seek>(reader: &mut BufReader<T>) -> Result<Format, Report> {
+    let start = reader.stream_position().to_report()?;
+    let format = detect_format_inner(reader);
+    reader.seek(SeekFrom::Start(start)).to_report()?;
+    format
+}
+
+fn detect_format_inner<T: Read + Seek>(reader: &mut BufReader<T>) -> Result<Format, Report> {
+    let mut header = [0u8; 16];
+    reader.read_exact(&mut header).to_report()?;
+
+    let format = match header {
+        [0xff, 0xd8, ..] => Format::Jpeg,
+        _ if &header == b"FUJIFILMCCD-RAW " => Format::Raf,
+        [_, _, _, _, b'f', b't', b'y', b'p', b'c', b'r', b'x', b' ', ..] => Format::Cr3,
+        [b'I', b'I', b'R', b'O' | b'S', ..] | [b'M', b'M', b'O', b'R', ..] => Format::Orf,
+        [b'I', b'I', b'U', 0, ..] => Format::Rw2,
+        [b'I', b'I', b'*', 0, _, _, _, _, b'C', b'R', 2, ..] => Format::Cr2,
+        [b'I', b'I', b'*' | b'+', 0, ..] | [b'M', b'M', 0, b'*' | b'+', ..] => {
+            reader.seek_relative(-16).to_report()?;
+            detect_tiff_variant(reader).to_report()?
+        }
+        [a, b, c, d, ..] => return Err(Error::UnknownFormat([a, b, c, d])).to_report(),
+    };
+    Ok(format)
+}
+
+/// Unreadable headers or IFD0s give the generic `Format::Tiff`
+fn detect_tiff_variant<T: Read + Seek>(reader: &mut BufReader<T>) -> Result<Format, Report> {
+    let Ok(values) = read_ifd0_values(reader, &[0x010f, 0xc612]) else {
+        return Ok(Format::Tiff);
+    };
+
+    let mut make = String::new();
+    for (tag, data) in values {
+        match tag {
+            0xc612 => return Ok(Format::Dng),
+            0x010f => {
+                let end = data.iter().position(|&x| x == 0).unwrap_or(data.len());
+                make = String::from_utf8_lossy(&data[..end]).to_ascii_uppercase();
+            }
+            _ => {}
+        }
+    }
+
+    let format = if make.starts_with("NIKON") {
+        Format::Nef
+    } else if make.starts_with("SONY") {
+        Format::Arw
+    } else if make.starts_with("PENTAX") || make.starts_with("RICOH") {
+        Format::Pef
+    } else if make.starts_with("SAMSUNG") {
+        Format::Srw
+    } else if make.starts_with("OLYMPUS") || make.starts_with("OM DIGITAL") {
+        Format::Orf
+    } else {
+        Format::Tiff
+    };
+    Ok(format)
+}
+
+/// Reads the values of `tags` from the IFD0 of the TIFF header at the current position.
+///
+/// Only the header and the IFD0 entries are read, unlike `TagQuery` neither the other
+/// entries nor the next IFDs can fail it. Values outside of the stream are left out.
+fn read_ifd0_values<T: Read + Seek>(
+    reader: &mut BufReader<T>,
+    tags: &[u16],
+) -> Result<Vec<(u16, Vec<u8>)>, Report> {
+    let start = reader.stream_position().to_report()?;
+    let mut header = [0u8; 16];
+    reader.read_exact(&mut header).to_report()?;
+
+    let is_le = header[0] == b'I';
+    let u16 = |x: &[u8]| {
+        let x = [x[0], x[1]];
+        if is_le {
+            u16::from_le_bytes(x)
+        } else {
+            u16::from_be_bytes(x)
+        }
+    };
+    let u64 = |x: &[u8]| {
+        let mut bytes = [0u8; 8];
+        if is_le {
+            bytes[..x.len()].copy_from_slice(x);
+            u64::from_le_bytes(bytes)
+        } else {
+            bytes[8 - x.len()..].copy_from_slice(x);
+            u64::from_be_bytes(bytes)
+        }
+    };
+
+    // BigTIFF has 8 bytes counts and offsets
+    let offset_size = if u16(&header[2..4]) == 0x2b { 8 } else { 4 };
+    let ifd_offset = if offset_size == 8 {
+        u64(&header[8..16])
+    } else {
+        u64(&header[4..8])
+    };
+    reader
+        .seek(SeekFrom::Start(start.saturating_add(ifd_offset)))
+        .to_report()?;
+
+    let count_size = if offset_size == 8 { 8 } else { 2 };
+    let mut count = [0u8; 8];
+    reader.read_exact(&mut count[..count_size]).to_report()?;
+    let count = u64(&count[..count_size]);
+    if count > Limits::default().max_entries as u64 {
+        return Err(Error::TooManyEntries(format_tag_path(&[0]), ifd_offset, count)).to_report();
+    }
+
+    let entry_size = 4 + 2 * offset_size;
+    let mut entries = vec![0u8; count as usize * entry_size];
+    reader.read_exact(&mut entries).to_report()?;
+
+    let mut values = vec![];
+    for entry in entries.chunks_exact(entry_size) {
+        let tag = u16(&entry[..2]);
+        if !tags.contains(&tag) {
+            continue;
+        }
+        let data_type = DataType::from_code(u16(&entry[2..4]));
+        let size = u64(&entry[4..4 + offset_size]).saturating_mul(data_type.size());
+        let value = &entry[4 + offset_size..];
+        let data = if size <= offset_size as u64 {
+            value[..size as usize].to_vec()
+        } else {
+            // longer values are plain strings or numbers, anything bigger is broken
+            if size > 0x10000 {
+                continue;
+            }
+            let mut data = vec![0u8; size as usize];
+            let read = reader
+                .seek(SeekFrom::Start(start.saturating_add(u64(value))))
+                .and_then(|_| reader.read_exact(&mut data));
+            if read.is_err() {
+                continue;
+            }
+            data
+        };
+        values.push((tag, data));
+    }
+    Ok(values)
+}
+
+/// Detects the format and finds every EXIF block it contains.
+///
+/// Each block can be parsed after seeking to its offset. Optional blocks that are
+/// missing from the file, like the CR3 timed metadata, are left out.
+pub fn open_any<T: Read + Seek>(
+    reader: &mut BufReader<T>,
+) -> Result<(Format, Vec<ExifBlock>), Report> {
+    let start = reader.stream_position().to_report()?;
+    let format = detect_format(reader).to_report()?;
+
+    let mut blocks = vec![];
+    let mut find = |part: u8, kind: BlockKind, required: bool| -> Result<(), Report> {
+        reader.seek(SeekFrom::Start(start)).to_report()?;
+        let found = match format {
+            Format::Raf => seek_header_raf(reader, part),
+            Format::Jpeg => jpeg::locate_segments(reader).and_then(|x| {
+                let exif = x.exif.ok_or(jpeg::Error::ExifNotFound).to_report()?;
+                reader.seek(SeekFrom::Start(exif.offset)).to_report()?;
+                Ok(())
+            }),
+            _ => Ok(()),
+        };
+        match found {
+            Ok(()) => blocks.push(ExifBlock {
+                kind,
+                offset: reader.stream_position().to_report()?,
+            }),
+            Err(e) if required => return Err(e),
+            Err(_) => {}
+        }
+        Ok(())
+    };
+
+    match format {
+        Format::Cr3 => {
+            reader.seek(SeekFrom::Start(start)).to_report()?;
+            let cr3 = Cr3::parse(reader).to_report()?;
+
+            let kinds = [
+                BlockKind::Tiff,
+                BlockKind::ExifIfd,
+                BlockKind::MakerNote,
+                BlockKind::GpsIfd,
+            ];
+            for (cmt, kind) in cr3.cmt.iter().zip(kinds) {
+                if let Some(cmt) = cmt {
+                    blocks.push(ExifBlock {
+                        kind,
+                        offset: cmt.offset,
+                    });
+                }
+            }
+            blocks.extend(cr3.ctmd.iter().map(|x| ExifBlock {
+                kind: match x.tag {
+                    0x8769 => BlockKind::ExifIfd,
+                    _ => BlockKind::MakerNote,
+                },
+                offset: x.offset,
+            }));
+        }
+        Format::Raf => {
+            find(0, BlockKind::Tiff, true).to_report()?;
+            find(1, BlockKind::RawIfd, false).to_report()?;
+        }
+        _ => {
+            find(0, BlockKind::Tiff, true).to_report()?;
+        }
+    }
+
+    reader.seek(SeekFrom::Start(start)).to_report()?;
+    Ok((format, blocks))
+}
@@ -0,0 +1,121 @@
+use crate::{DateTime, IFDItem, IFDNode};
+
+/// A bearing from one of the GPS direction tags
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Direction {
+    pub degrees: f64,
+    /// Relative to the magnetic north instead of the true north
+    pub is_magnetic: bool,
+}
+
+/// The decoded GPS IFD (0x8825)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GpsInfo {
+    pub version: Option<[u8; 4]>,
+    /// Signed decimal degrees, negative for the south
+    pub latitude: Option<f64>,
+    /// Signed decimal degrees, negative for the west
+    pub longitude: Option<f64>,
+    /// In meters, negative below the sea level
+    pub altitude: Option<f64>,
+    /// GPSDateStamp and GPSTimeStamp in UTC
+    pub time: Option<DateTime>,
+    pub satellites: Option<String>,
+    /// False when the measurement is interrupted
+    pub is_active: Option<bool>,
+    /// 2 or 3 dimensional measurement
+    pub measure_mode: Option<u8>,
+    /// Dilution of precision
+    pub dop: Option<f64>,
+    /// In km/h, converted from GPSSpeedRef
+    pub speed: Option<f64>,
+    /// Direction of movement
+    pub track: Option<Direction>,
+    /// Direction the camera points to
+    pub image_direction: Option<Direction>,
+    /// Like `WGS-84`
+    pub map_datum: Option<String>,
+    /// Like `GPS`, `CELLID`, `WLAN` or `MANUAL`
+    pub processing_method: Option<String>,
+    pub area_information: Option<String>,
+    pub differential: Option<bool>,
+    /// In meters
+    pub horizontal_error: Option<f64>,
+}
+
+impl GpsInfo {
+    /// Decodes the entries returned by `get`, which looks a tag up in the GPS IFD.
+    ///
+    /// Values out of their valid ranges are left as `None`. Missing refs are treated
+    /// as north, east, above the sea level, km/h and the true north.
+    pub fn decode<'a>(get: impl Fn(u16) -> Option<&'a IFDItem>) -> Self {
+        let string = |tag| Some(get(tag)?.text()?.text);
+        let real = |tag| get(tag)?.rational()?.to_f64();
+        let starts_with = |tag, c| string(tag).is_some_and(|x| x.starts_with(c));
+        let direction = |ref_tag, tag| {
+            Some(Direction {
+                degrees: real(tag).filter(|x| (0.0..=360.0).contains(x))?,
+                is_magnetic: starts_with(ref_tag, 'M'),
+            })
+        };
+
+        let sign = |is_negative: bool, x: f64| if is_negative { -x } else { x };
+        let latitude = dms(get(0x0002))
+            .filter(|x| *x <= 90.0)
+            .map(|x| sign(starts_with(0x0001, 'S'), x));
+        let longitude = dms(get(0x0004))
+            .filter(|x| *x <= 180.0)
+            .map(|x| sign(starts_with(0x0003, 'W'), x));
+        // 1 is below the sea level, Exif 3.0 adds 3 for below the ellipsoid
+        let is_below = get(0x0005).is_some_and(|x| matches!(x.raw()[0], 1 | 3));
+        let altitude = real(0x0006).map(|x| sign(is_below, x));
+
+        let time = string(0x001d).and_then(|date| {
+            let time = get(0x0007)?.rationals()?;
+            DateTime::from_gps(&date, &time)
+        });
+
+        let speed_factor = match string(0x000c).as_deref() {
+            Some("M") => 1.609344,
+            Some("N") => 1.852,
+            _ => 1.0,
+        };
+
+        Self {
+            version: get(0x0000).and_then(|x| x.raw().get(..4)?.try_into().ok()),
+            latitude,
+            longitude,
+            altitude,
+            time,
+            satellites: string(0x0008),
+            is_active: string(0x0009).map(|x| x != "V"),
+            measure_mode: string(0x000a).and_then(|x| x.parse().ok()),
+            dop: real(0x000b),
+            speed: real(0x000d).map(|x| x * speed_factor),
+            track: direction(0x000e, 0x000f),
+            image_direction: direction(0x0010, 0x0011),
+            map_datum: string(0x0012),
+            processing_method: get(0x001b).and_then(|x| x.user_comment()).map(|x| x.text),
+            area_information: get(0x001c).and_then(|x| x.user_comment()).map(|x| x.text),
+            differential: get(0x001e).and_then(|x| x.u16s()).map(|x| x[0] == 1),
+            horizontal_error: real(0x001f),
+        }
+    }
+
+    /// Decodes a GPS IFD node of `parse_exif_tree`
+    pub fn from_node(node: &IFDNode) -> Self {
+        Self::decode(|tag| node.get(tag))
+    }
+
+    /// `(latitude, longitude)` when both are present
+    pub fn position(&self) -> Option<(f64, f64)> {
+        Some((self.latitude?, self.longitude?))
+    }
+}
+
+/// Degrees, minutes and seconds to decimal degrees
+fn dms(item: Option<&IFDItem>) -> Option<f64> {
+    let x = item?.rationals()?;
+    let [d, m, s] = [x.first()?, x.get(1)?, x.get(2)?].map(|x| x.to_f64());
+    Some(d? + m? / 60.0 + s? / 3600.0)
+}
@@ -0,0 +1,208 @@
+use std::io::{BufReader, Read, Seek, SeekFrom};
+
+use crate::{IFDItem, IFDNode, ToReport, Value};
+use erreport::Report;
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("Tag {0:#06x} is required")]
+    MissingTag(u16),
+    #[error("Tag {0:#06x} has an invalid value: {1}")]
+    InvalidValue(u16, u64),
+    #[error("{0} offsets and {1} byte counts do not pair up")]
+    CountMismatch(usize, usize),
+    #[error("{0} segments are expected but {1} are found")]
+    SegmentCount(u64, usize),
+    #[error("{0} x {1} segments in {2} planes are too many")]
+    Overflow(u64, u64, u64),
+    #[error("Segment({0}) with {2} bytes at {1:#x} is out of the stream bounds")]
+    OutOfBounds(usize, u64, u64),
+    #[error("Segment({0}) is not found")]
+    SegmentNotFound(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layout {
+    Strips { rows_per_strip: u32 },
+    /// Tiles are stored padded to the full tile size
+    Tiles { tile_width: u32, tile_length: u32 },
+}
+
+/// A strip or tile of the image data
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Segment {
+    /// Stream position of the payload
+    pub offset: u64,
+    pub length: u64,
+    /// The area the segment covers, clipped to the image size
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+    /// Always 0 unless PlanarConfiguration is 2
+    pub plane: u16,
+}
+
+/// The strips or tiles of an IFD, checked against its layout tags and the stream length
+#[derive(Debug, Clone)]
+pub struct ImageData {
+    pub width: u32,
+    pub height: u32,
+    pub layout: Layout,
+    /// SamplesPerPixel when the samples are stored in separate planes, otherwise 1
+    pub planes: u16,
+    segments: Vec<Segment>,
+}
+
+/// The first SHORT, LONG or LONG8 element
+pub(crate) fn uint(item: &IFDItem) -> Option<u64> {
+    uints(item)?.first().copied()
+}
+
+pub(crate) fn uints(item: &IFDItem) -> Option<Vec<u64>> {
+    let result: Vec<u64> = match item.value() {
+        Value::Short(x) => x.iter().map(|&x| x as u64).collect(),
+        Value::Long(x) | Value::Ifd(x) => x.iter().map(|&x| x as u64).collect(),
+        Value::Long8(x) | Value::Ifd8(x) => x.into_vec(),
+        _ => return None,
+    };
+    Some(result).filter(|x| !x.is_empty())
+}
+
+impl ImageData {
+    /// Builds the segments from the entries returned by `get`, which looks a tag up
+    /// in the IFD holding the image.
+    ///
+    /// The offsets are relative to the TIFF header at `tiff_offset`, like
+    /// `ExifData::tiff_offset`. `stream_len` is the length of the whole stream.
+    pub fn decode<'a>(
+        get: impl Fn(u16) -> Option<&'a IFDItem>,
+        tiff_offset: u64,
+        stream_len: u64,
+    ) -> Result<Self, Report> {
+        let required = |tag| -> Result<u64, Error> {
+            let x = get(tag).and_then(uint).ok_or(Error::MissingTag(tag))?;
+            match x {
+                1..=0xffff_ffff => Ok(x),
+                _ => Err(Error::InvalidValue(tag, x)),
+            }
+        };
+        let width = required(0x0100).to_report()?;
+        let height = required(0x0101).to_report()?;
+
+        let planes = match get(0x011c).and_then(uint).unwrap_or(1) {
+            1 => 1,
+            2 => get(0x0115).and_then(uint).unwrap_or(1),
+            x => Err(Error::InvalidValue(0x011c, x)).to_report()?,
+        };
+        if planes == 0 || planes > 0xffff {
+            Err(Error::InvalidValue(0x0115, planes)).to_report()?;
+        }
+
+        let (layout, offsets_tag, counts_tag, across, down, seg_width, seg_height) =
+            if get(0x0144).is_some() {
+                let tile_width = required(0x0142).to_report()?;
+                let tile_length = required(0x0143).to_report()?;
+                let layout = Layout::Tiles {
+                    tile_width: tile_width as u32,
+                    tile_length: tile_length as u32,
+                };
+                let across = width.div_ceil(tile_width);
+                let down = height.div_ceil(tile_length);
+                (layout, 0x0144, 0x0145, across, down, tile_width, tile_length)
+            } else {
+                // missing or 2^32 - 1 means a single strip
+                let rows_per_strip = get(0x0116).and_then(uint).unwrap_or(height).clamp(1, height);
+                let layout = Layout::Strips {
+                    rows_per_strip: rows_per_strip as u32,
+                };
+                let down = height.div_ceil(rows_per_strip);
+                (layout, 0x0111, 0x0117, 1, down, width, rows_per_strip)
+            };
+
+        let offsets = get(offsets_tag)
+            .and_then(uints)
+            .ok_or(Error::MissingTag(offsets_tag))
+            .to_report()?;
+        let counts = get(counts_tag)
+            .and_then(uints)
+            .ok_or(Error::MissingTag(counts_tag))
+            .to_report()?;
+        if offsets.len() != counts.len() {
+            Err(Error::CountMismatch(offsets.len(), counts.len())).to_report()?;
+        }
+        let overflow = || Error::Overflow(across, down, planes);
+        let per_plane = across.checked_mul(down).ok_or_else(overflow).to_report()?;
+        let expected = per_plane.checked_mul(planes).ok_or_else(overflow).to_report()?;
+        if expected != offsets.len() as u64 {
+            Err(Error::SegmentCount(expected, offsets.len())).to_report()?;
+        }
+
+        let mut segments = Vec::with_capacity(offsets.len());
+        for (i, (offset, length)) in offsets.into_iter().zip(counts).enumerate() {
+            let offset = tiff_offset.saturating_add(offset);
+            if offset.saturating_add(length) > stream_len {
+                Err(Error::OutOfBounds(i, offset, length)).to_report()?;
+            }
+            let index = i as u64 % per_plane;
+            let (x, y) = (index % across * seg_width, index / across * seg_height);
+            segments.push(Segment {
+                offset,
+                length,
+                x: x as u32,
+                y: y as u32,
+                width: seg_width.min(width - x) as u32,
+                height: seg_height.min(height - y) as u32,
+                plane: (i as u64 / per_plane) as u16,
+            });
+        }
+
+        Ok(Self {
+            width: width as u32,
+            height: height as u32,
+            layout,
+            planes: planes as u16,
+            segments,
+        })
+    }
+
+    /// Builds the segments of a node of `parse_exif_tree`, see `decode`
+    pub fn from_node(node: &IFDNode, tiff_offset: u64, stream_len: u64) -> Result<Self, Report> {
+        Self::decode(|tag| node.get(tag), tiff_offset, stream_len)
+    }
+
+    /// In the storage order: row by row, and plane by plane for planar images
+    pub fn segments(&self) -> &[Segment] {
+        &self.segments
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Segment> {
+        self.segments.iter()
+    }
+
+    /// Reads the payload of the n-th segment
+    pub fn read_segment<T: Read + Seek>(
+        &self,
+        reader: &mut BufReader<T>,
+        index: usize,
+    ) -> Result<Vec<u8>, Report> {
+        let segment = self
+            .segments
+            .get(index)
+            .ok_or(Error::SegmentNotFound(index))
+            .to_report()?;
+        reader.seek(SeekFrom::Start(segment.offset)).to_report()?;
+        let mut result = vec![0u8; segment.length as usize];
+        reader.read_exact(&mut result).to_report()?;
+        Ok(result)
+    }
+}
+
+impl<'a> IntoIterator for &'a ImageData {
+    type Item = &'a Segment;
+    type IntoIter = std::slice::Iter<'a, Segment>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.segments.iter()
+    }
+}
@@ -1,5 +1,5 @@
 use std::{
-    io::{self, BufReader, Read, Seek},
+    io::{self, BufReader, Read, Seek, SeekFrom},
     vec,
 };
 
@@ -14,9 +14,11 @@ pub enum Error {
     InvalidHeader(u16),
     #[error("JPEG tail error: {0:x}")]
     InvalidTail(u16),
+    #[error("No EXIF APP1 segment found in JPEG")]
+    ExifNotFound,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct JPEG<'a> {
     pub dqt: &'a [u8],
     pub sof: SOF,
@@ -25,7 +27,7 @@ pub struct JPEG<'a> {
     pub sos: SOS<'a>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct SOF {
     pub id: u8,
     pub precision: u8,
@@ -35,7 +37,7 @@ pub struct SOF {
     pub components: Vec<(u8, u8, u8, u8)>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct SOS<'a> {
     /// [2bytes for 1 component: Scan component selector + DC entropy coding table destination selector + AC entropy coding table destination selector]
     pub scan_header: Vec<(u8, u8, u8)>,
@@ -57,19 +59,133 @@ pub struct DHT<'a> {
     pub huff_vals: Vec<&'a [u8]>,
 }
 
-// =======================================================================================
+/// The payload of an APPn segment, after its identifier
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Segment {
+    pub marker: u16,
+    pub offset: u64,
+    pub length: u64,
+}
+
+/// The frame header of a SOFn segment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Frame {
+    /// 0xffc0 for baseline, 0xffc2 for progressive and 0xffc3 for lossless JPEG
+    pub marker: u16,
+    pub width: u16,
+    pub height: u16,
+}
+
+/// Positions of the metadata segments found by `locate_segments`
+#[derive(Debug, Default)]
+pub struct Segments {
+    /// Starts from the TIFF header after `Exif\0\0`
+    pub exif: Option<Segment>,
+    /// Starts from the XMP packet after the XMP namespace
+    pub xmp: Option<Segment>,
+    /// The ICC profile chunks in their sequence order, each after its 14 bytes chunk header
+    pub icc: Vec<Segment>,
+    /// Starts from the MPF TIFF header after `MPF\0`
+    pub mpf: Option<Segment>,
+    /// The first frame header
+    pub frame: Option<Frame>,
+}
+
+const EXIF_ID: &[u8] = b"Exif\0\0";
+const XMP_ID: &[u8] = b"http://ns.adobe.com/xap/1.0/\0";
+const ICC_ID: &[u8] = b"ICC_PROFILE\0";
+const MPF_ID: &[u8] = b"MPF\0";
+
+/// Walks the JPEG markers from the current position until the first scan,
+/// then restores the reader position.
+pub fn locate_segments<T: Read + Seek>(reader: &mut BufReader<T>) -> Result<Segments, Report> {
+    let start = reader.stream_position().to_report()?;
+    let segments = walk_segments(reader);
+    reader.seek(SeekFrom::Start(start)).to_report()?;
+    segments
+}
+
+fn walk_segments<T: Read + Seek>(reader: &mut BufReader<T>) -> Result<Segments, Report> {
+    let read_u16 = |reader: &mut BufReader<T>| -> Result<u16, Report> {
+        let mut x = [0u8; 2];
+        reader.read_exact(&mut x).to_report()?;
+        Ok(u16::from_be_bytes(x))
+    };
+
+    let header = read_u16(reader).to_report()?;
+    if header != 0xffd8 {
+        return Err(Error::InvalidHeader(header)).to_report();
+    }
+
+    let mut segments = Segments::default();
+    let mut icc = vec![];
+    loop {
+        let mut x = [0u8; 1];
+        reader.read_exact(&mut x).to_report()?;
+        if x[0] != 0xff {
+            break; // not a marker, the segments are broken from here
+        }
+        // skip the fill bytes
+        while x[0] == 0xff {
+            reader.read_exact(&mut x).to_report()?;
+        }
+        let marker = 0xff00 | x[0] as u16;
+        match marker {
+            0xffd0..=0xffd7 | 0xff01 => continue, // no payload
+            0xffd9 | 0xffda => break,            // end of image or start of scan
+            _ => {}
+        }
+
+        let length = read_u16(reader).to_report()? as u64;
+        if length < 2 {
+            break;
+        }
+        let offset = reader.stream_position().to_report()?;
+        let length = length - 2;
 
-impl<'a> Default for JPEG<'a> {
-    fn default() -> Self {
-        JPEG {
-            dqt: &[],
-            sof: SOF::default(),
-            dht: vec![],
-            data: &[],
-            sos: SOS::default(),
+        // SOF0..SOF15 except DHT, JPG and DAC
+        if let 0xffc0..=0xffc3 | 0xffc5..=0xffc7 | 0xffc9..=0xffcb | 0xffcd..=0xffcf = marker {
+            if segments.frame.is_none() && length >= 5 {
+                let mut x = [0u8; 5];
+                reader.read_exact(&mut x).to_report()?;
+                segments.frame = Some(Frame {
+                    marker,
+                    width: u16::from_be_bytes([x[3], x[4]]),
+                    height: u16::from_be_bytes([x[1], x[2]]),
+                });
+            }
         }
+        if let 0xffe1 | 0xffe2 = marker {
+            let mut id = vec![0u8; (length as usize).min(XMP_ID.len())];
+            reader.read_exact(&mut id).to_report()?;
+
+            let segment = |id_len: usize| Segment {
+                marker,
+                offset: offset + id_len as u64,
+                length: length - id_len as u64,
+            };
+            if marker == 0xffe1 && id.starts_with(EXIF_ID) && segments.exif.is_none() {
+                segments.exif = Some(segment(EXIF_ID.len()));
+            } else if marker == 0xffe1 && id.starts_with(XMP_ID) {
+                segments.xmp = Some(segment(XMP_ID.len()));
+            } else if marker == 0xffe2 && id.starts_with(ICC_ID) && length >= 14 {
+                let sequence = id.get(ICC_ID.len()).copied().unwrap_or_default();
+                icc.push((sequence, segment(ICC_ID.len() + 2)));
+            } else if marker == 0xffe2 && id.starts_with(MPF_ID) {
+                segments.mpf = Some(segment(MPF_ID.len()));
+            }
+        }
+
+        reader.seek(SeekFrom::Start(offset + length)).to_report()?;
     }
+
+    icc.sort_by_key(|(sequence, _)| *sequence);
+    segments.icc = icc.into_iter().map(|(_, x)| x).collect();
+    Ok(segments)
 }
+
+// =======================================================================================
+
 impl<'a> JPEG<'a> {
     pub fn new(bytes: &'a [u8]) -> Result<Self, Report> {
         let cursor = &mut 0;
@@ -102,7 +218,7 @@ impl<'a> JPEG<'a> {
                 }
                 0xffc4 => {
                     let size = bytes.u16(cursor).to_report()? as usize;
-                    let dhts = DHT::parse_from_bytes(&bytes.slice(cursor, size - 2).to_report()?)
+                    let dhts = DHT::parse_from_bytes(bytes.slice(cursor, size - 2).to_report()?)
                         .to_report()?;
                     jpeg.dht.extend(dhts);
                 }
@@ -120,29 +236,6 @@ impl<'a> JPEG<'a> {
     }
 }
 
-impl<'a> Default for SOS<'a> {
-    fn default() -> Self {
-        SOS {
-            scan_header: vec![],
-            ss: 0,
-            se: 0,
-            ah: 0,
-            al: 0,
-            body: &[],
-        }
-    }
-}
-impl Default for SOF {
-    fn default() -> Self {
-        SOF {
-            id: 0,
-            precision: 0,
-            height: 0,
-            width: 0,
-            components: vec![],
-        }
-    }
-}
 impl SOF {
     fn parse_from_bytes(id: u8, bytes: &[u8]) -> Result<Self, Report> {
         let cursor = &mut 0;
@@ -213,7 +306,7 @@ impl<'a> SOS<'a> {
 }
 
 impl<'a> DHT<'a> {
-    fn parse_from_bytes(bytes: &[u8]) -> Result<Vec<DHT>, Report> {
+    fn parse_from_bytes(bytes: &[u8]) -> Result<Vec<DHT<'_>>, Report> {
         let mut result = vec![];
 
         let cursor = &mut 0;
@@ -256,7 +349,7 @@ impl Read4JPEG for [u8] {
     fn u8(&self, cursor: &mut usize) -> Result<u8, Error> {
         let data = self
             .get(*cursor)
-            .ok_or_else(|| Error::IndexError(*cursor))?;
+            .ok_or(Error::IndexError(*cursor))?;
         *cursor += 1;
         Ok(*data)
     }
@@ -264,8 +357,8 @@ impl Read4JPEG for [u8] {
         let mut x = [0u8; 2];
         let data = self
             .get(*cursor..*cursor + 2)
-            .ok_or_else(|| Error::IndexError(*cursor))?;
-        x.copy_from_slice(&data);
+            .ok_or(Error::IndexError(*cursor))?;
+        x.copy_from_slice(data);
         *cursor += 2;
         Ok(u16::from_be_bytes(x))
     }
@@ -1,16 +1,39 @@
 #![allow(dead_code)]
 #![allow(unused_imports)]
 
-use phf::phf_map;
 use std::{
-    collections::HashMap,
-    io::{BufReader, Read, Seek},
+    collections::{HashMap, HashSet},
+    io::{BufReader, Read, Seek, SeekFrom},
 };
 
 erreport::gen_trait_to_report!();
 use erreport::Report;
 
+pub mod cr3;
+mod datetime;
+mod format;
+mod gps;
+pub mod image_data;
 pub mod jpeg;
+pub mod makernote;
+mod metadata;
+mod preview;
+mod query;
+pub mod raf;
+pub mod tags;
+mod text;
+mod tree;
+mod value;
+
+pub use datetime::DateTime;
+pub use format::{detect_format, open_any, BlockKind, ExifBlock, Format};
+pub use gps::{Direction, GpsInfo};
+pub use metadata::Metadata;
+pub use preview::{copy_largest_preview, previews, Preview, PreviewKind, PreviewSource};
+pub use query::{format_tag_path, parse_tag_path, ExifData, TagQuery};
+pub use text::{Encoding, Text};
+pub use tree::{parse_exif_tree, IFDNode};
+pub use value::{DataType, Rational, SRational, Value};
 
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
@@ -18,6 +41,63 @@ pub enum Error {
     InvalidTiffHeader([u8; 2]),
     #[error("Part({0}) is not defined for this file type")]
     PartNotDefined(u8),
+    #[error("Invalid tag path: {0:?}")]
+    InvalidTagPath(String),
+    #[error("Part({0}) is not found in this file")]
+    PartNotFound(u8),
+    #[error("IFD({0}) at {1:#x} points back to itself or one of its parents")]
+    IfdLoop(String, u64),
+    #[error("IFD({0}) at {1:#x} exceeds the limit of {2} IFDs")]
+    TooManyIfds(String, u64, usize),
+    #[error("IFD({0}) at {1:#x} is nested too deep")]
+    MaxDepthExceeded(String, u64),
+    #[error("IFD({0}) at {1:#x} has too many entries: {2}")]
+    TooManyEntries(String, u64, u64),
+    #[error("{1} bytes at {0:#x} are out of the stream bounds")]
+    OutOfBounds(i64, u64),
+    #[error("Unknown file format, the file starts with {0:02x?}")]
+    UnknownFormat([u8; 4]),
+}
+
+/// Bounds of the IFD traversal, so broken or crafted files fail instead of running away
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    /// How many sub IFD levels are followed, IFD0 and its next IFDs are level 0
+    pub max_depth: usize,
+    /// The entry count an IFD may declare
+    pub max_entries: u16,
+    /// How many IFDs are parsed in total, an IFD shared by several pointers counts each time
+    pub max_ifds: usize,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            max_depth: 8,
+            max_entries: 1000,
+            max_ifds: 1000,
+        }
+    }
+}
+
+/// Generates the accessors of a number type, reading the first element or all of them.
+///
+/// They are `None` for the format codes other than `$types`, the signed integers also
+/// read the unsigned ones of the same size.
+macro_rules! gen_typed_accessors {
+    ($one:ident, $many:ident, $t:ident, $($types:ident)|+, $doc:literal) => {
+        #[doc = $doc]
+        pub fn $one(&self) -> Option<$t> {
+            self.$many()?.first().copied()
+        }
+        #[doc = $doc]
+        pub fn $many(&self) -> Option<Box<[$t]>> {
+            if !matches!(self.data_type(), $(DataType::$types)|+) {
+                return None;
+            }
+            Some(self.decode($t::from_le_bytes, $t::from_be_bytes)).filter(|x| !x.is_empty())
+        }
+    };
 }
 
 #[derive(Debug)]
@@ -25,8 +105,9 @@ pub struct IFDItem {
     is_le: bool,
     tag: u16,
     format: [u8; 2],
-    size: [u8; 4],
-    value: [u8; 4],
+    size: u64,
+    value: [u8; 8], // BigTIFF holds up to 8 bytes inline, classic TIFF only uses the first 4
+    is_big: bool,
     actual_value: Option<Box<[u8]>>,
     addr: u64,
 }
@@ -35,17 +116,101 @@ impl IFDItem {
     pub fn raw(&self) -> &[u8] {
         match self.actual_value.as_ref() {
             Some(x) => x,
-            None => &self.value,
+            None if self.is_big => &self.value,
+            None => &self.value[..4],
         }
     }
     pub fn addr(&self) -> usize {
         self.addr as usize
     }
-    pub fn size(&self) -> u32 {
+    pub fn tag(&self) -> u16 {
+        self.tag
+    }
+    pub fn format(&self) -> u16 {
         if self.is_le {
-            u32::from_le_bytes(self.size)
+            u16::from_le_bytes(self.format)
         } else {
-            u32::from_be_bytes(self.size)
+            u16::from_be_bytes(self.format)
+        }
+    }
+    pub fn data_type(&self) -> DataType {
+        DataType::from_code(self.format())
+    }
+    /// The element count, saturated at `u32::MAX` for BigTIFF, see `size64`
+    pub fn size(&self) -> u32 {
+        self.size.min(u32::MAX as u64) as u32
+    }
+    /// The element count, BigTIFF entries may hold more than `u32::MAX`
+    pub fn size64(&self) -> u64 {
+        self.size
+    }
+    /// The bytes of the `size` elements, without the padding of inline values
+    fn data(&self) -> &[u8] {
+        let raw = self.raw();
+        let len = self.size.saturating_mul(self.data_type().size());
+        &raw[..raw.len().min(len as usize)]
+    }
+    fn decode<const N: usize, X>(&self, le: fn([u8; N]) -> X, be: fn([u8; N]) -> X) -> Box<[X]> {
+        let f = if self.is_le { le } else { be };
+        self.data()
+            .chunks_exact(N)
+            .map(|x| f(x.try_into().unwrap()))
+            .collect()
+    }
+    fn decode_pairs<X, R>(
+        &self,
+        le: fn([u8; 4]) -> X,
+        be: fn([u8; 4]) -> X,
+        pair: fn(X, X) -> R,
+    ) -> Box<[R]> {
+        let f = if self.is_le { le } else { be };
+        self.data()
+            .chunks_exact(8)
+            .map(|x| pair(f(x[..4].try_into().unwrap()), f(x[4..].try_into().unwrap())))
+            .collect()
+    }
+
+    gen_typed_accessors!(
+        i8,
+        i8s,
+        i8,
+        SByte | Byte | Undefined,
+        "Only for SBYTE, BYTE and UNDEFINED entries"
+    );
+    gen_typed_accessors!(i16, i16s, i16, SShort | Short, "Only for SSHORT and SHORT entries");
+    gen_typed_accessors!(i32, i32s, i32, SLong | Long, "Only for SLONG and LONG entries");
+    gen_typed_accessors!(i64, i64s, i64, SLong8 | Long8, "Only for SLONG8 and LONG8 entries");
+    gen_typed_accessors!(f32, f32s, f32, Float, "Only for FLOAT entries");
+    gen_typed_accessors!(f64, f64s, f64, Double, "Only for DOUBLE entries");
+
+    /// Decodes every element according to the format code
+    pub fn value(&self) -> Value {
+        let u16s = || self.decode(u16::from_le_bytes, u16::from_be_bytes);
+        let u32s = || self.decode(u32::from_le_bytes, u32::from_be_bytes);
+        let u64s = || self.decode(u64::from_le_bytes, u64::from_be_bytes);
+
+        match self.data_type() {
+            DataType::Byte => Value::Byte(self.data().into()),
+            DataType::Ascii => {
+                let data = self.data();
+                let end = data.iter().position(|&x| x == 0).unwrap_or(data.len());
+                Value::Ascii(String::from_utf8_lossy(&data[..end]).into_owned())
+            }
+            DataType::Short => Value::Short(u16s()),
+            DataType::Long => Value::Long(u32s()),
+            DataType::Rational => Value::Rational(self.decode_rationals()),
+            DataType::SByte => Value::SByte(self.decode(i8::from_le_bytes, i8::from_be_bytes)),
+            DataType::Undefined => Value::Undefined(self.data().into()),
+            DataType::SShort => Value::SShort(self.decode(i16::from_le_bytes, i16::from_be_bytes)),
+            DataType::SLong => Value::SLong(self.decode(i32::from_le_bytes, i32::from_be_bytes)),
+            DataType::SRational => Value::SRational(self.decode_srationals()),
+            DataType::Float => Value::Float(self.decode(f32::from_le_bytes, f32::from_be_bytes)),
+            DataType::Double => Value::Double(self.decode(f64::from_le_bytes, f64::from_be_bytes)),
+            DataType::Ifd => Value::Ifd(u32s()),
+            DataType::Long8 => Value::Long8(u64s()),
+            DataType::SLong8 => Value::SLong8(self.decode(i64::from_le_bytes, i64::from_be_bytes)),
+            DataType::Ifd8 => Value::Ifd8(u64s()),
+            DataType::Unknown(x) => Value::Unknown(x, self.data().into()),
         }
     }
     pub fn u16(&self) -> u16 {
@@ -57,73 +222,123 @@ impl IFDItem {
         }
     }
     pub fn u32(&self) -> u32 {
+        let v = [self.value[0], self.value[1], self.value[2], self.value[3]];
+        if self.is_le {
+            u32::from_le_bytes(v)
+        } else {
+            u32::from_be_bytes(v)
+        }
+    }
+    /// Only BigTIFF entries can hold a whole u64 inline
+    pub fn u64(&self) -> u64 {
         if self.is_le {
-            u32::from_le_bytes(self.value)
+            u64::from_le_bytes(self.value)
         } else {
-            u32::from_be_bytes(self.value)
+            u64::from_be_bytes(self.value)
         }
     }
+    /// The UTF-8 string before the first NUL, without trailing spaces,
+    /// see `text` for the other encodings
     pub fn str(&self) -> Option<&str> {
-        self.actual_value.as_ref().and_then(|bytes| {
-            std::str::from_utf8(bytes)
-                .ok()
-                .and_then(|x| x.strip_suffix('\0'))
-        })
+        let data = self.data();
+        let end = data.iter().position(|&x| x == 0).unwrap_or(data.len());
+        std::str::from_utf8(&data[..end])
+            .ok()
+            .map(|x| x.trim_end())
+    }
+    /// Decodes ASCII, UTF-8 or Latin-1, `None` for blank strings
+    pub fn text(&self) -> Option<Text> {
+        text::decode_bytes(self.data())
+    }
+    /// Decodes UCS-2 stored in BYTE arrays, like the Windows XP tags 0x9c9b..0x9c9f
+    pub fn ucs2_text(&self) -> Option<Text> {
+        text::decode_ucs2(self.data(), true)
     }
+    /// Decodes strings with an 8 bytes charset id, like UserComment
+    pub fn user_comment(&self) -> Option<Text> {
+        text::decode_prefixed(self.data(), self.is_le)
+    }
+    /// BYTE and SHORT entries, widened to u16
     pub fn u16s(&self) -> Option<Box<[u16]>> {
-        self.actual_value.as_ref().map(|bytes| {
-            bytes
-                .chunks_exact(2)
-                .map(|x| {
-                    let v: [u8; 2] = [x[0], x[1]];
-                    if self.is_le {
-                        u16::from_le_bytes(v)
-                    } else {
-                        u16::from_be_bytes(v)
-                    }
-                })
-                .collect()
-        })
+        let result: Box<[u16]> = match self.data_type() {
+            DataType::Byte => self.data().iter().map(|&x| x as u16).collect(),
+            DataType::Short => self.decode(u16::from_le_bytes, u16::from_be_bytes),
+            _ => return None,
+        };
+        Some(result).filter(|x| !x.is_empty())
     }
+    /// BYTE, SHORT, LONG and IFD entries, widened to u32
     pub fn u32s(&self) -> Option<Box<[u32]>> {
-        self.actual_value.as_ref().map(|bytes| {
-            bytes
-                .chunks_exact(4)
-                .map(|x| {
-                    let v: [u8; 4] = [x[0], x[1], x[2], x[3]];
-                    if self.is_le {
-                        u32::from_le_bytes(v)
-                    } else {
-                        u32::from_be_bytes(v)
-                    }
-                })
-                .collect()
-        })
+        let result: Box<[u32]> = match self.data_type() {
+            DataType::Long | DataType::Ifd => self.decode(u32::from_le_bytes, u32::from_be_bytes),
+            _ => self.u16s()?.iter().map(|&x| x as u32).collect(),
+        };
+        Some(result).filter(|x| !x.is_empty())
+    }
+    /// BYTE, SHORT, LONG, IFD, LONG8 and IFD8 entries, widened to u64
+    pub fn u64s(&self) -> Option<Box<[u64]>> {
+        let result: Box<[u64]> = match self.data_type() {
+            DataType::Long8 | DataType::Ifd8 => self.decode(u64::from_le_bytes, u64::from_be_bytes),
+            _ => self.u32s()?.iter().map(|&x| x as u64).collect(),
+        };
+        Some(result).filter(|x| !x.is_empty())
+    }
+    fn decode_rationals(&self) -> Box<[Rational]> {
+        self.decode_pairs(u32::from_le_bytes, u32::from_be_bytes, Rational::new)
+    }
+    fn decode_srationals(&self) -> Box<[SRational]> {
+        self.decode_pairs(i32::from_le_bytes, i32::from_be_bytes, SRational::new)
     }
+    /// Only for RATIONAL entries
+    pub fn rational(&self) -> Option<Rational> {
+        self.rationals()?.first().copied()
+    }
+    /// Only for RATIONAL entries
+    pub fn rationals(&self) -> Option<Box<[Rational]>> {
+        (self.data_type() == DataType::Rational).then(|| self.decode_rationals())
+    }
+    /// Only for SRATIONAL entries
+    pub fn srational(&self) -> Option<SRational> {
+        self.srationals()?.first().copied()
+    }
+    /// Only for SRATIONAL entries
+    pub fn srationals(&self) -> Option<Box<[SRational]>> {
+        (self.data_type() == DataType::SRational).then(|| self.decode_srationals())
+    }
+    /// RATIONAL and SRATIONAL entries, a zero denominator gives an infinity or NaN.
+    /// The unsigned integers of `u64s` are also read, since some tags allow both.
     pub fn r64s(&self) -> Option<Box<[f64]>> {
-        self.actual_value.as_ref().map(|bytes| {
-            bytes
-                .chunks_exact(8)
-                .map(|x| {
-                    let left: [u8; 4] = [x[0], x[1], x[2], x[3]];
-                    let right: [u8; 4] = [x[4], x[5], x[6], x[7]];
-
-                    if self.is_le {
-                        i32::from_le_bytes(left) as f64 / u32::from_le_bytes(right) as f64
-                    } else {
-                        i32::from_be_bytes(left) as f64 / u32::from_be_bytes(right) as f64
-                    }
-                })
-                .collect()
-        })
+        let result: Box<[f64]> = match self.data_type() {
+            DataType::Rational => self
+                .decode_rationals()
+                .iter()
+                .map(|x| x.num as f64 / x.den as f64)
+                .collect(),
+            DataType::SRational => self
+                .decode_srationals()
+                .iter()
+                .map(|x| x.num as f64 / x.den as f64)
+                .collect(),
+            _ => self.u64s()?.iter().map(|&x| x as f64).collect(),
+        };
+        Some(result).filter(|x| !x.is_empty())
     }
 }
 
 struct TiffParser<T: Read + Seek> {
     is_le: bool,
-    addr_offset: i32, // offset for actual value address, useful for internal tiff blocks
+    is_big: bool, // BigTIFF, with 8 bytes counts and offsets
+    addr_offset: i64, // offset for actual value address, useful for internal tiff blocks
+    stream_len: u64,
     reader: BufReader<T>,
-    path_map: HashMap<&'static [u16], u16>,
+    path_map: HashMap<Vec<u16>, u16>,
+    explore: bool, // collect every IFD reachable through `EXPLORE_POINTERS` instead of `path_map` only
+    limits: Limits,
+    visited: HashSet<u64>, // stream positions of the IFDs on the current chain and its parent chains
+    ifd_count: usize,
+    make: Option<String>, // Make of IFD0, for choosing the makernote parser
+    model: Option<String>, // Model of IFD0, for the keys of the encrypted makernote records
+    makernote: Option<&'static dyn makernote::MakerNoteParser>, // set inside makernote IFDs
 }
 
 macro_rules! gen_num_helper {
@@ -147,16 +362,14 @@ macro_rules! to_bytes {
     }};
 }
 
-/// first 4 bytes => (shift N bytes, needs to add makernotes' offset)
-static MAKERNOTES_HEADER_SIZE: phf::Map<[u8; 4], (i64, Option<i32>)> = phf_map! {
-    [0x50, 0x61, 0x6e, 0x61] => (12, None), // panasonic
-    [0x4f, 0x4c, 0x59, 0x4d] => (12, Some(0)), // olympus
-    [0x4e, 0x69, 0x6b, 0x6f] => (18, Some(10)), // nikon
-};
+/// Pointer tags followed in explore mode:
+/// ExifIFD, GPS, Interop, SubIFDs, MakerNote and DNGPrivateData
+const EXPLORE_POINTERS: [u16; 6] = [0x8769, 0x8825, 0xa005, 0x014a, 0x927c, 0xc634];
 
 type Collector = HashMap<(u16, u16), IFDItem>;
 
 impl<T: Read + Seek> TiffParser<T> {
+    gen_num_helper!(u64, 8);
     gen_num_helper!(u32, 4);
     gen_num_helper!(u16, 2);
 
@@ -200,16 +413,43 @@ impl<T: Read + Seek> TiffParser<T> {
     }
     fn read_no_shift<const N: usize>(&mut self) -> Result<[u8; N], Report> {
         let ret = self.read_shift();
-        self.reader.seek_relative(N as i64 * -1).to_report()?;
+        self.reader.seek_relative(-(N as i64)).to_report()?;
         ret
     }
-    fn seek_ab(&mut self, loc: u32) -> Result<(), Report> {
+    fn seek_ab(&mut self, loc: u64) -> Result<(), Report> {
+        self.check_bounds(loc, 0).to_report()?;
         let pos = self.reader.stream_position().to_report()?;
         self.reader
-            .seek_relative(loc as i64 - pos as i64 + self.addr_offset as i64)
+            .seek_relative(loc as i64 - pos as i64 + self.addr_offset)
             .to_report()?;
         Ok(())
     }
+    /// Checks `len` bytes at `loc` against the stream length
+    fn check_bounds(&self, loc: u64, len: u64) -> Result<(), Error> {
+        let start = (loc as i64).wrapping_add(self.addr_offset);
+        if start < 0 || (start as u64).saturating_add(len) > self.stream_len {
+            return Err(Error::OutOfBounds(start, len));
+        }
+        Ok(())
+    }
+    /// Reads a BigTIFF u64 or a classic TIFF u32
+    fn read_offset(&mut self) -> Result<u64, Report> {
+        if self.is_big {
+            let x = self.read_shift::<8>().to_report()?;
+            Ok(self.u64(x))
+        } else {
+            let x = self.read_shift::<4>().to_report()?;
+            Ok(self.u32(x) as u64)
+        }
+    }
+    /// The offset stored in the value field of an entry
+    fn value_offset(&self, value: [u8; 8]) -> u64 {
+        if self.is_big {
+            self.u64(value)
+        } else {
+            self.u32([value[0], value[1], value[2], value[3]]) as u64
+        }
+    }
     fn recover_pos(&mut self, loc: u64) -> Result<(), Report> {
         let pos = self.reader.stream_position().to_report()?;
         self.reader
@@ -223,87 +463,70 @@ impl<T: Read + Seek> TiffParser<T> {
         Ok(())
     }
 
-    fn new(
-        mut reader: BufReader<T>,
-        path_lst: impl AsRef<[&'static [u16]]>,
-    ) -> Result<Self, Report> {
+    fn new(mut reader: BufReader<T>, path_map: HashMap<Vec<u16>, u16>) -> Result<Self, Report> {
         let init_pos = reader.stream_position().to_report()?;
-        let addr_offset = init_pos as i32 + {
+        let addr_offset = {
             // jpg detect
             let mut header = [0u8; 2];
             reader.read_exact(&mut header).to_report()?;
+            reader.seek_relative(-2).to_report()?;
             if header == [0xff, 0xd8] {
-                let mut header = [0u8; 2];
-                reader.read_exact(&mut header).to_report()?;
-
-                if header == [0xff, 0xe0] {
-                    // is JFIF
-                    reader.seek_relative(26).to_report()?;
-                    30
-                } else {
-                    // is EXIF
-                    reader.seek_relative(8).to_report()?;
-                    12
-                }
+                let exif = jpeg::locate_segments(&mut reader)
+                    .to_report()?
+                    .exif
+                    .ok_or(jpeg::Error::ExifNotFound)
+                    .to_report()?;
+                reader.seek(SeekFrom::Start(exif.offset)).to_report()?;
+                exif.offset as i64
             } else {
-                reader.seek_relative(-2).to_report()?;
-                0
+                init_pos as i64
             }
         };
 
-        let is_le = {
-            let mut header = [0u8; 2];
+        let (is_le, is_big) = {
+            let mut header = [0u8; 4];
             reader.read_exact(&mut header).to_report()?;
-            reader.seek_relative(-2).to_report()?;
-            match header {
-                [0x49, 0x49] => true,
-                [0x4d, 0x4d] => false,
-                _ => Err(Error::InvalidTiffHeader(header)).to_report()?,
-            }
+            reader.seek_relative(-4).to_report()?;
+            tiff_byte_order(header).to_report()?
         };
 
-        let path_map = path_lst
-            .as_ref()
-            .into_iter()
-            .enumerate()
-            .map(|(i, x)| (*x, i as u16))
-            .collect();
+        let stream_len = {
+            let pos = reader.stream_position().to_report()?;
+            let len = reader.seek(SeekFrom::End(0)).to_report()?;
+            reader.seek(SeekFrom::Start(pos)).to_report()?;
+            len
+        };
 
         Ok(Self {
             is_le,
+            is_big,
             addr_offset,
+            stream_len,
             reader,
             path_map,
+            explore: false,
+            limits: Limits::default(),
+            visited: HashSet::new(),
+            ifd_count: 0,
+            make: None,
+            model: None,
+            makernote: None,
         })
     }
 
     fn check_actual_value(
         &mut self,
         format: [u8; 2],
-        size: [u8; 4],
-        addr: [u8; 4],
+        size: u64,
+        value: [u8; 8],
     ) -> Result<Option<Box<[u8]>>, Report> {
         let format = self.u16(format);
-        let format_size = match format {
-            0x0001 => 1u32, // u8
-            0x0002 => 1,    // string
-            0x0003 => 2,    // u16
-            0x0004 => 4,    // u32
-            0x0005 => 8,
-            0x0006 => 1,
-            0x0007 => 1,
-            0x0008 => 2,
-            0x0009 => 4,
-            0x000a => 8,
-            0x000b => 4,
-            0x000c => 8,
-            0x000d => 4,
-            0x000e => 8,
-            _ => 1,
-        };
-        let total_size = self.u32(size) * format_size;
-        if total_size > 4 || format == 0x0002 {
-            let addr = self.u32(addr);
+        let format_size = DataType::from_code(format).size();
+        let total_size = size.saturating_mul(format_size);
+        let inline_size = if self.is_big { 8 } else { 4 };
+        if total_size > inline_size {
+            let addr = self.value_offset(value);
+            self.check_bounds(addr, total_size).to_report()?;
             let pos = self.reader.stream_position().to_report()?;
             self.seek_ab(addr).to_report()?;
             let actual_value = self.read_to_vec(total_size as usize).to_report()?;
@@ -315,25 +538,155 @@ impl<T: Read + Seek> TiffParser<T> {
     }
 
     fn parse_ifd(&mut self, path: Vec<u16>, collector: &mut Collector) -> Result<(), Report> {
-        let entry_count = {
+        // IFDs are only kept in `visited` while their chain is walked, so an IFD shared by
+        // several pointers is parsed for each of them and only real cycles fail
+        let mut chain = vec![];
+        let result = self.parse_chain(path, collector, &mut chain);
+        for x in chain {
+            self.visited.remove(&x);
+        }
+        result
+    }
+
+    fn parse_chain(
+        &mut self,
+        mut path: Vec<u16>,
+        collector: &mut Collector,
+        chain: &mut Vec<u64>,
+    ) -> Result<(), Report> {
+        // the next IFD chain is walked in a loop, only sub IFDs go deeper
+        let mut is_first = true;
+        loop {
+            let offset = self.get_addr().to_report()?;
+            if !self.visited.insert(offset) {
+                Err(Error::IfdLoop(format_tag_path(&path), offset)).to_report()?;
+            }
+            chain.push(offset);
+            let result = self.parse_single_ifd(&path, collector);
+            let next_ifd_offset = match result {
+                Ok(x) => x,
+                // broken IFDs after the first one of a chain are skipped while exploring
+                Err(_) if self.explore && !is_first => break,
+                Err(e) => return Err(e),
+            };
+            // a next IFD outside of the stream is treated as the end of the chain
+            if next_ifd_offset == 0
+                || !self.needs_next_ifd(&path)
+                || self.check_bounds(next_ifd_offset, 2).is_err()
+            {
+                break;
+            }
+            self.seek_ab(next_ifd_offset).to_report()?;
+            if let Some(x) = path.last_mut() {
+                *x += 1;
+            }
+            is_first = false;
+        }
+        Ok(())
+    }
+
+    /// Whether a listed path lies further down the chain of `path`, so broken next IFDs
+    /// nobody asked for do not fail the query
+    fn needs_next_ifd(&self, path: &[u16]) -> bool {
+        let Some((&id, parent)) = path.split_last() else {
+            return false;
+        };
+        self.explore
+            || self
+                .path_map
+                .keys()
+                .any(|x| x.len() > parent.len() && x.starts_with(parent) && x[parent.len()] > id)
+    }
+
+    /// Parses the IFD at the current position and its sub IFDs, returns the next IFD offset
+    fn parse_single_ifd(&mut self, path: &[u16], collector: &mut Collector) -> Result<u64, Report> {
+        let offset = self.get_addr().to_report()?;
+        self.ifd_count += 1;
+        if self.ifd_count > self.limits.max_ifds {
+            let max_ifds = self.limits.max_ifds;
+            Err(Error::TooManyIfds(format_tag_path(path), offset, max_ifds)).to_report()?;
+        }
+        if path.len() / 2 > self.limits.max_depth {
+            Err(Error::MaxDepthExceeded(format_tag_path(path), offset)).to_report()?;
+        }
+
+        if self.explore && !self.path_map.contains_key(path) {
+            let path_index = self.path_map.len() as u16;
+            self.path_map.insert(path.to_vec(), path_index);
+        }
+
+        let dig_deep = self.parse_entries(path, offset, collector).to_report()?;
+
+        let next_ifd_offset = self.read_offset().to_report()?;
+
+        let (addr_offset, is_le, is_big, makernote) =
+            (self.addr_offset, self.is_le, self.is_big, self.makernote);
+        for (addr, path) in dig_deep {
+            // makernotes and embedded JPEGs may change them
+            self.addr_offset = addr_offset;
+            self.is_le = is_le;
+            self.is_big = is_big;
+            self.makernote = makernote;
+
+            // broken or unknown sub IFDs are skipped while exploring
+            let result = self.parse_sub_ifd(addr, path, collector);
+            if !self.explore {
+                result.to_report()?;
+            }
+        }
+        self.addr_offset = addr_offset;
+        self.is_le = is_le;
+        self.is_big = is_big;
+        self.makernote = makernote;
+
+        Ok(next_ifd_offset)
+    }
+
+    /// Collects the entries of the IFD at `offset`, returns the sub IFDs to dig into
+    fn parse_entries(
+        &mut self,
+        path: &[u16],
+        offset: u64,
+        collector: &mut Collector,
+    ) -> Result<Vec<(u64, Vec<u16>)>, Report> {
+        let entry_count = if self.is_big {
+            let x = self.read_shift::<8>().to_report()?;
+            self.u64(x)
+        } else {
             let x = self.read_shift::<2>().to_report()?;
-            self.u16(x)
+            self.u16(x) as u64
         };
+        if entry_count > self.limits.max_entries as u64 {
+            Err(Error::TooManyEntries(format_tag_path(path), offset, entry_count)).to_report()?;
+        }
 
-        let mut path_deep = path.clone();
+        let mut path_deep = path.to_vec();
         let path_deep_len = path_deep.len();
         path_deep.extend([0u16, 0]);
 
+        // Sony's SR2Private IFD points to the encrypted SR2SubIFD
+        let is_sr2private = path.len() >= 2 && path[path.len() - 2] == 0xc634;
+        let mut sr2 = [None; 3]; // offset (0x7200), length (0x7201) and key (0x7221)
+
         let mut dig_deep = vec![];
+        let mut items = vec![];
         for _ in 0..entry_count {
             let addr = self.get_addr().to_report()?;
             let tag = self.read_shift::<2>().to_report()?;
             let tag = self.u16(tag);
 
             let format = self.read_shift::<2>().to_report()?;
-            let size = self.read_shift::<4>().to_report()?;
-            let value = self.read_shift::<4>().to_report()?;
-            let actual_value = self.check_actual_value(format, size, value).to_report()?;
+            let size = self.read_offset().to_report()?;
+            let mut value = [0u8; 8];
+            if self.is_big {
+                value = self.read_shift::<8>().to_report()?;
+            } else {
+                value[..4].copy_from_slice(&self.read_shift::<4>().to_report()?);
+            }
+            let mut actual_value = self.check_actual_value(format, size, value).to_report()?;
+            if let (Some(makernote), Some(x)) = (self.makernote, actual_value.as_mut()) {
+                makernote.decipher(tag, x);
+            }
 
             let ifd_item = IFDItem {
                 is_le: self.is_le,
@@ -341,6 +694,7 @@ impl<T: Read + Seek> TiffParser<T> {
                 format,
                 size,
                 value,
+                is_big: self.is_big,
                 actual_value,
                 addr
             };
@@ -349,73 +703,155 @@ impl<T: Read + Seek> TiffParser<T> {
             if let Some(x) = path_deep.get_mut(path_deep_len..) {
                 x[0] = tag;
             }
-            // save addr and path for later deeper digging
-            if self.path_map.contains_key(path_deep.as_slice()) {
-                if let (Some(addrs), 0x0004) = (ifd_item.u32s(), self.u16(format)) {
+            if is_sr2private {
+                match tag {
+                    0x7200 => sr2[0] = Some(ifd_item.u32()),
+                    0x7201 => sr2[1] = Some(ifd_item.u32()),
+                    0x7221 => sr2[2] = Some(ifd_item.u32()),
+                    _ => {}
+                }
+            }
+            // save addr and path for later deeper digging, the encrypted SR2SubIFD is
+            // parsed after the entries
+            let is_dig_path = self.path_map.contains_key(path_deep.as_slice())
+                && !(is_sr2private && tag == 0x7200);
+            if is_dig_path || self.is_explore_pointer(&ifd_item) {
+                let addrs = match self.u16(format) {
+                    0x0004 | 0x000d => ifd_item
+                        .u32s()
+                        .map(|x| x.iter().map(|&x| x as u64).collect::<Vec<_>>()),
+                    0x0010 | 0x0012 => ifd_item.u64s().map(|x| x.to_vec()),
+                    _ => None,
+                };
+                if let Some(addrs) = addrs {
                     dig_deep.extend(addrs.into_iter().enumerate().map(|(i, addr)| {
                         let mut path = path_deep.clone();
                         if let Some(last) = path.last_mut() {
                             *last = (i * 100) as u16; // set path ifd id to 0, 100, 200, 300
                         }
-                        (*addr, path)
+                        (addr, path)
                     }))
                 } else {
-                    let addr = self.u32(value);
+                    let addr = self.value_offset(value);
                     dig_deep.push((addr, path_deep.clone()));
                 }
             }
 
-            if let Some(&path_index) = self.path_map.get(path.as_slice()) {
-                collector.insert((path_index, tag), ifd_item);
+            if path == [0] {
+                match tag {
+                    0x010f => self.make = ifd_item.text().map(|x| x.text),
+                    0x0110 => self.model = ifd_item.text().map(|x| x.text),
+                    _ => {}
+                }
             }
-        }
-
-        let next_ifd_offset = {
-            let x = self.read_shift::<4>().to_report()?;
-            self.u32(x)
-        };
-        if next_ifd_offset != 0 && next_ifd_offset < 0xffffff {
-            self.seek_ab(next_ifd_offset).to_report()?;
-
-            let mut next_path = path.clone();
-            if let Some(x) = next_path.last_mut() {
-                *x += 1;
+            if self.path_map.contains_key(path) {
+                items.push(ifd_item);
             }
-            self.parse_ifd(next_path, collector).to_report()?;
         }
 
-        let addr_offset = self.addr_offset;
-        for (addr, path) in dig_deep {
-            self.addr_offset = addr_offset; // offset recover
-            self.seek_ab(addr).to_report()?;
+        // the keys of some encrypted makernote records are other entries of the IFD
+        if let Some(makernote) = self.makernote {
+            makernote.decrypt(&mut items, self.model.as_deref());
+        }
+        if let Some(&path_index) = self.path_map.get(path) {
+            collector.extend(items.into_iter().map(|x| ((path_index, x.tag), x)));
+        }
 
-            // detect if is jpg header
-            if self.read_no_shift::<2>().to_report()? == [0xff, 0xd8] {
-                self.seek_re(12).to_report()?; // pass JPEG header
-                self.addr_offset = self.reader.stream_position().to_report()? as i32;
-                self.shift_from_tiff_header().to_report()?;
-            }
-            // detect if is makernotes
-            let check = self.read_no_shift::<4>().to_report()?;
-            if let Some(&(shift, addr_offset)) = MAKERNOTES_HEADER_SIZE.get(&check) {
-                if let Some(offset) = addr_offset {
-                    self.addr_offset += self.reader.stream_position().to_report()? as i32 + offset;
+        if let [Some(offset), Some(length), Some(key)] = sr2 {
+            let mut sr2_path = path.to_vec();
+            sr2_path.extend([0x7200, 0]);
+            if self.explore || self.path_map.contains_key(&sr2_path) {
+                let pos = self.get_addr().to_report()?;
+                let result = self.parse_sr2_subifd(offset, length, key, sr2_path, collector);
+                if !self.explore {
+                    result.to_report()?;
                 }
-                self.seek_re(shift).to_report()?;
+                self.recover_pos(pos).to_report()?;
             }
+        }
+        Ok(dig_deep)
+    }
 
-            self.parse_ifd(path, collector).to_report()?;
+    fn is_explore_pointer(&self, ifd_item: &IFDItem) -> bool {
+        if !self.explore || !EXPLORE_POINTERS.contains(&ifd_item.tag) || ifd_item.u64() == 0 {
+            return false;
         }
+        // DNGPrivateData is only an IFD pointer in the Sony SR2 form,
+        // otherwise it holds the Adobe private data itself
+        ifd_item.tag != 0xc634 || ifd_item.actual_value.is_none()
+    }
 
+    fn parse_sub_ifd(
+        &mut self,
+        addr: u64,
+        path: Vec<u16>,
+        collector: &mut Collector,
+    ) -> Result<(), Report> {
+        self.seek_ab(addr).to_report()?;
+
+        // detect if is jpg header
+        if self.read_no_shift::<2>().to_report()? == [0xff, 0xd8] {
+            let exif = jpeg::locate_segments(&mut self.reader)
+                .to_report()?
+                .exif
+                .ok_or(jpeg::Error::ExifNotFound)
+                .to_report()?;
+            self.reader
+                .seek(SeekFrom::Start(exif.offset))
+                .to_report()?;
+            self.addr_offset = exif.offset as i64;
+            // the embedded TIFF header has its own byte order and may be a BigTIFF one
+            let header = self.read_no_shift().to_report()?;
+            (self.is_le, self.is_big) = tiff_byte_order(header).to_report()?;
+            self.shift_from_tiff_header().to_report()?;
+        }
+        let is_makernote = path.len() >= 2 && path[path.len() - 2] == 0x927c;
+        self.makernote = None;
+        if is_makernote && !self.seek_makernote().to_report()? {
+            return Ok(());
+        }
+
+        self.parse_ifd(path, collector).to_report()?;
         Ok(())
     }
 
-    fn shift_from_tiff_header(&mut self) -> Result<(), Report> {
-        self.seek_re(4).to_report()?;
-        let ifd_offset = {
-            let x = self.read_shift::<4>().to_report()?;
-            self.u32(x)
+    /// Moves to the IFD of the makernote at the current position and switches to its
+    /// byte order and offset base, returns `false` when it is not an IFD
+    fn seek_makernote(&mut self) -> Result<bool, Report> {
+        let start = self.get_addr().to_report()?;
+        let len = self.stream_len.saturating_sub(start).min(makernote::HEADER_LEN as u64);
+        let header = self.read_to_vec(len as usize).to_report()?;
+        self.recover_pos(start).to_report()?;
+
+        // unknown makernotes are tried as an IFD at the start
+        self.makernote = makernote::find(self.make.as_deref(), &header);
+        let layout = self
+            .makernote
+            .map(|x| x.layout(&header, self.is_le))
+            .unwrap_or(makernote::Layout::after_signature(0));
+        let makernote::Layout::Ifd {
+            ifd_offset,
+            is_le,
+            base,
+        } = layout
+        else {
+            return Ok(false);
         };
+
+        if let Some(x) = is_le {
+            self.is_le = x;
+        }
+        if let makernote::OffsetBase::MakerNote(x) = base {
+            self.addr_offset = start as i64 + x as i64;
+        }
+        self.seek_re(ifd_offset as i64).to_report()?;
+        Ok(true)
+    }
+
+    fn shift_from_tiff_header(&mut self) -> Result<(), Report> {
+        // BigTIFF has 2 more u16 fields, the offset byte size (always 8) and a padding
+        self.seek_re(if self.is_big { 8 } else { 4 }).to_report()?;
+        let ifd_offset = self.read_offset().to_report()?;
         self.seek_ab(ifd_offset).to_report()?;
         Ok(())
     }
@@ -428,126 +864,119 @@ impl<T: Read + Seek> TiffParser<T> {
 
         Ok(result)
     }
-    fn parse_sony_sr2private(
+    /// Decrypts Sony's SR2SubIFD, whose offsets point into the encrypted block
+    fn parse_sr2_subifd(
         &mut self,
-        sr2private_index: u16, // the index of sr2private path in path_map
+        offset: u32,
+        length: u32,
+        key: u32,
         path: Vec<u16>,
         collector: &mut Collector,
     ) -> Result<(), Report> {
-        match (
-            collector.get(&(sr2private_index, 0x7200)),
-            collector.get(&(sr2private_index, 0x7201)),
-            collector.get(&(sr2private_index, 0x7221)),
-        ) {
-            (Some(offset_ifd), Some(length_ifd), Some(key_ifd)) => {
-                let offset = self.u32(offset_ifd.value);
-                let length = self.u32(length_ifd.value);
-                let key = self.u32(key_ifd.value);
-
-                self.seek_ab(offset).to_report()?;
-                let sr2private_bytes = self.read_to_vec(length as usize).to_report()?;
-                let decrypted = self.sony_decrypt(&sr2private_bytes, key);
-                let mut new_parser = TiffParser {
-                    is_le: self.is_le,
-                    addr_offset: -(offset as i32),
-                    reader: BufReader::new(std::io::Cursor::new(decrypted)),
-                    path_map: self.path_map.clone(),
-                };
-                new_parser.parse_ifd(path, collector).to_report()?;
-            }
-            _ => {}
-        }
-        Ok(())
+        let offset = offset as u64;
+        self.check_bounds(offset, length as u64).to_report()?;
+        self.seek_ab(offset).to_report()?;
+        let sr2private_bytes = self.read_to_vec(length as usize).to_report()?;
+        let decrypted = self.sony_decrypt(&sr2private_bytes, key);
+        let mut new_parser = TiffParser {
+            is_le: self.is_le,
+            is_big: false,
+            addr_offset: -(offset as i64),
+            stream_len: decrypted.len() as u64,
+            reader: BufReader::new(std::io::Cursor::new(decrypted)),
+            path_map: self.path_map.clone(),
+            explore: self.explore,
+            limits: self.limits,
+            visited: HashSet::new(),
+            ifd_count: self.ifd_count,
+            make: self.make.clone(),
+            model: self.model.clone(),
+            makernote: None,
+        };
+        let result = new_parser.parse_ifd(path, collector);
+        // the paths found while exploring are keyed by their index in the path map
+        self.path_map = new_parser.path_map;
+        self.ifd_count = new_parser.ifd_count;
+        result
     }
 }
 
-/// The return data contains (exif_info_hashmap, is_little_endian_marker)
+/// The return data contains (exif_info_hashmap, is_little_endian_marker).
+///
+/// Sony's encrypted SR2SubIFD is decrypted when a path lists it, like
+/// `0 -> 0xc634 -> 0 -> 0x7200 -> 0`.
 pub fn parse_exif<T: Read + Seek>(
     reader: BufReader<T>,
     path_dig: &[&'static [u16]],
-    sony_decrypt_index: Option<(u16, usize)>, // (sr2private_path_index, sr2private_offset_path_index)
 ) -> Result<(Collector, bool), Report> {
-    let mut parser = TiffParser::new(reader, path_dig).to_report()?;
-    let mut result = parser.parse().to_report()?;
-
-    if let Some((sr2private_path_index, sr2private_offset_path_index)) = sony_decrypt_index {
-        parser
-            .parse_sony_sr2private(
-                sr2private_path_index,
-                path_dig[sr2private_offset_path_index].to_vec(),
-                &mut result,
-            )
-            .to_report()?;
-    }
+    let query = TagQuery::from_paths(path_dig);
+    let mut parser = TiffParser::new(reader, query.path_map()).to_report()?;
+    let result = parser.parse().to_report()?;
 
     Ok((result, parser.is_le))
 }
 
-fn seek_tiff_header<T: Read + Seek>(reader: &mut BufReader<T>) -> Result<(), Report> {
-    loop {
-        let mut x = [0u8; 4];
-        reader.read_exact(&mut x).to_report()?;
-        if x == [0x49, 0x49, 0x2a, 0x00] {
-            reader.seek_relative(-4).to_report()?;
-            break Ok(());
-        }
+/// (is_le, is_big) of the TIFF header starting with `header`
+fn tiff_byte_order(header: [u8; 4]) -> Result<(bool, bool), Error> {
+    match header {
+        [0x49, 0x49, 0x2b, 0x00] => Ok((true, true)),
+        [0x4d, 0x4d, 0x00, 0x2b] => Ok((false, true)),
+        [0x49, 0x49, ..] => Ok((true, false)),
+        [0x4d, 0x4d, ..] => Ok((false, false)),
+        _ => Err(Error::InvalidTiffHeader([header[0], header[1]])),
     }
 }
 
-fn seek_cr3_cmt<T: Read + Seek>(reader: &mut BufReader<T>, no: u8) -> Result<(), Report> {
+fn seek_tiff_header<T: Read + Seek>(reader: &mut BufReader<T>) -> Result<(), Report> {
     loop {
         let mut x = [0u8; 4];
         reader.read_exact(&mut x).to_report()?;
-        if x == [0x43, 0x4d, 0x54, no] {
+        if x == [0x49, 0x49, 0x2a, 0x00] {
+            reader.seek_relative(-4).to_report()?;
             break Ok(());
         }
     }
 }
-fn seek_cr3_header<T: Read + Seek>(reader: &mut BufReader<T>, index: i8) -> Result<(), Report> {
-    reader.seek_relative(0x1a00002i64).to_report()?;
-    let mut curr = -1i8;
-    while curr < index {
-        let mut x = [0u8; 4];
-        reader.read_exact(&mut x).to_report()?;
-        if x == [0x7c, 0x92, 0, 0] {
-            curr += 1;
-        }
-    }
-    Ok(())
-}
 
+/// Seeks to the TIFF header of a CR3 block:
+/// 0 => CMT1 (IFD0), 1 => CMT2 (ExifIFD), 2 => CMT3 (makernote), 5 => CMT4 (GPS),
+/// 3 and 4 => the first and second makernote blocks of the CTMD track
 pub fn seek_header_cr3<T: Read + Seek>(reader: &mut BufReader<T>, part: u8) -> Result<(), Report> {
-    match part {
-        0 => {
-            seek_cr3_cmt(reader, 0x31).to_report()?;
-        }
-        1 => {
-            seek_cr3_cmt(reader, 0x32).to_report()?;
-        }
-        2 => {
-            seek_cr3_cmt(reader, 0x33).to_report()?;
-        }
-        3 => {
-            seek_cr3_header(reader, 0).to_report()?;
-        }
-        4 => {
-            seek_cr3_header(reader, 1).to_report()?;
-        }
-        _ => Err(Error::PartNotDefined(part)).to_report()?,
+    if part > 5 {
+        Err(Error::PartNotDefined(part)).to_report()?;
     }
+    let cr3 = cr3::Cr3::parse(reader).to_report()?;
+    let offset = cr3
+        .part_offset(part)
+        .ok_or(Error::PartNotFound(part))
+        .to_report()?;
+    reader.seek(SeekFrom::Start(offset)).to_report()?;
     Ok(())
 }
 
+/// Seeks to the TIFF data of a RAF block:
+/// 0 => the embedded JPEG, 1 => the CFA section holding the raw IFDs
 pub fn seek_header_raf<T: Read + Seek>(reader: &mut BufReader<T>, part: u8) -> Result<(), Report> {
+    let start = reader.stream_position().to_report()?;
+    let raf = raf::Raf::parse(reader).to_report()?;
+
     match part {
         0 => {
-            // cut first 148 bytes
-            reader.seek_relative(148).to_report()?;
+            reader
+                .seek(SeekFrom::Start(start + raf.jpeg_offset as u64))
+                .to_report()?;
         }
         1 => {
-            // jump to the next tiff header
-            reader.seek_relative(160 + 4).to_report()?;
-            seek_tiff_header(reader).to_report()?;
+            reader
+                .seek(SeekFrom::Start(start + raf.cfa_offset as u64))
+                .to_report()?;
+            let mut x = [0u8; 4];
+            reader.read_exact(&mut x).to_report()?;
+            reader.seek_relative(-4).to_report()?;
+            if x != [0x49, 0x49, 0x2a, 0x00] {
+                // jump to the next tiff header
+                seek_tiff_header(reader).to_report()?;
+            }
         }
         _ => Err(Error::PartNotDefined(part)).to_report()?,
     }
@@ -555,22 +984,33 @@ pub fn seek_header_raf<T: Read + Seek>(reader: &mut BufReader<T>, part: u8) -> R
     Ok(())
 }
 
+/// Declares the tags to collect at compile time.
+///
+/// Besides `PATH_LST` and one `(path_index, tag)` constant per tag, it also generates
+/// a `tag_query()` function returning the equivalent [`TagQuery`] with every tag named
+/// after its constant.
 #[macro_export]
 macro_rules! gen_tags_info {
     [$($path:literal)->* { $($body:tt)* } $($tails:tt)*] => {
-        gen_tags_info![@path(&[$($path),*],) @defs() @path_index(0; $($body)*) $($tails)*];
+        gen_tags_info![@path(&[$($path),*],) @defs() @names() @path_index(0; $($body)*) $($tails)*];
     };
 
-    [@path($($p:tt)*) @defs($($d:tt)*) @path_index($pi:expr;) $($path:literal)->* { $($body:tt)* } $($tails:tt)*] => {
-        gen_tags_info![@path($($p)* &[$($path),*],) @defs($($d)*) @path_index($pi + 1; $($body)*) $($tails)*];
+    [@path($($p:tt)*) @defs($($d:tt)*) @names($($n:tt)*) @path_index($pi:expr;) $($path:literal)->* { $($body:tt)* } $($tails:tt)*] => {
+        gen_tags_info![@path($($p)* &[$($path),*],) @defs($($d)*) @names($($n)*) @path_index($pi + 1; $($body)*) $($tails)*];
     };
 
-    [@path($($p:tt)*) @defs($($d:tt)*) @path_index($pi:expr; $tag:literal $id:ident $($inner_tails:tt)*) $($tails:tt)*] => {
-        gen_tags_info![@path($($p)*) @defs($($d)* pub const $id:&(u16, u16) = &($pi, $tag);) @path_index($pi; $($inner_tails)*) $($tails)*];
+    [@path($($p:tt)*) @defs($($d:tt)*) @names($($n:tt)*) @path_index($pi:expr; $tag:literal $id:ident $($inner_tails:tt)*) $($tails:tt)*] => {
+        gen_tags_info![@path($($p)*) @defs($($d)* pub const $id:&(u16, u16) = &($pi, $tag);) @names($($n)* $id) @path_index($pi; $($inner_tails)*) $($tails)*];
     };
 
-    [@path($($p:tt)*) @defs($($d:tt)*) @path_index($pi:expr;)] => {
+    [@path($($p:tt)*) @defs($($d:tt)*) @names($($n:ident)*) @path_index($pi:expr;)] => {
         pub const PATH_LST : &[&'static [u16]] = &[$($p)*];
         $($d)*
+
+        pub fn tag_query() -> $crate::TagQuery {
+            let mut query = $crate::TagQuery::from_paths(PATH_LST);
+            $(query.name(stringify!($n), *$n);)*
+            query
+        }
     }
 }
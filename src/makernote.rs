@@ -0,0 +1,283 @@
+use std::sync::RwLock;
+
+use crate::IFDItem;
+
+pub mod canon;
+pub mod nikon;
+pub mod sony;
+
+/// How many bytes of the makernote are handed to `MakerNoteParser`
+pub const HEADER_LEN: usize = 32;
+
+/// What the offsets inside a makernote IFD are relative to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OffsetBase {
+    /// The TIFF header of the file, like the other IFDs
+    Parent,
+    /// The makernote start plus n bytes, like the TIFF header embedded in Nikon's
+    MakerNote(u64),
+}
+
+/// How a makernote is stored
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layout {
+    Ifd {
+        /// Position of the entry count, from the makernote start
+        ifd_offset: u64,
+        /// `None` keeps the byte order of the file
+        is_le: Option<bool>,
+        base: OffsetBase,
+    },
+    /// Not an IFD, the makernote is not walked
+    Opaque,
+}
+
+impl Layout {
+    /// An IFD right after a signature of `len` bytes, using the offsets of the file
+    pub const fn after_signature(len: u64) -> Self {
+        Self::Ifd {
+            ifd_offset: len,
+            is_le: None,
+            base: OffsetBase::Parent,
+        }
+    }
+}
+
+/// Describes the makernotes of a vendor, selected by the Make of IFD0 and the signature
+pub trait MakerNoteParser: Send + Sync {
+    /// `make` is the Make of IFD0 when it is seen before the makernote, `header` holds up
+    /// to `HEADER_LEN` bytes of the makernote. Both come from `TagQuery::set_makernote_make`
+    /// for standalone makernote blocks, with an empty `header`
+    fn matches(&self, make: Option<&str>, header: &[u8]) -> bool;
+    /// `is_le` is the byte order of the IFD holding the makernote
+    fn layout(&self, header: &[u8], is_le: bool) -> Layout;
+    /// Deciphers the out-of-line value of a makernote entry in place, for vendors
+    /// enciphering some of their tags
+    fn decipher(&self, _tag: u16, _data: &mut [u8]) {}
+    /// Decrypts the values of a makernote IFD in place once all its entries are read,
+    /// for vendors keying their records with other entries. `model` is the Model of IFD0
+    fn decrypt(&self, _entries: &mut [IFDItem], _model: Option<&str>) {}
+}
+
+static REGISTRY: RwLock<Vec<&'static dyn MakerNoteParser>> = RwLock::new(Vec::new());
+
+/// Adds a parser, which takes precedence over the built-in ones and the ones
+/// registered before it. Registering the same parser again does nothing
+pub fn register(parser: &'static dyn MakerNoteParser) {
+    let mut registry = REGISTRY.write().unwrap_or_else(|x| x.into_inner());
+    if !registry.iter().any(|&x| std::ptr::eq(x, parser)) {
+        registry.push(parser);
+    }
+}
+
+/// The first matching parser
+pub fn find(make: Option<&str>, header: &[u8]) -> Option<&'static dyn MakerNoteParser> {
+    let registry = REGISTRY.read().unwrap_or_else(|x| x.into_inner());
+    registry
+        .iter()
+        .rev()
+        .chain(BUILTIN.iter())
+        .find(|x| x.matches(make, header))
+        .copied()
+}
+
+/// Signatures come first, since makes like NIKON also have makernotes without one
+const BUILTIN: &[&dyn MakerNoteParser] = &[
+    &Nikon, &Olympus, &Panasonic, &Fujifilm, &Pentax, &Sony, &Apple, &Kodak, &Canon, &Samsung,
+];
+
+fn has_make(make: Option<&str>, prefix: &str) -> bool {
+    make.is_some_and(|x| x.trim().to_ascii_uppercase().starts_with(prefix))
+}
+
+/// `II` or `MM` at `pos`, `None` for anything else
+fn byte_order(header: &[u8], pos: usize) -> Option<bool> {
+    match header.get(pos..pos + 2)? {
+        b"II" => Some(true),
+        b"MM" => Some(false),
+        _ => None,
+    }
+}
+
+fn u32_at(header: &[u8], pos: usize, is_le: bool) -> Option<u32> {
+    let x = header.get(pos..pos + 4)?.try_into().ok()?;
+    Some(if is_le {
+        u32::from_le_bytes(x)
+    } else {
+        u32::from_be_bytes(x)
+    })
+}
+
+/// Type 3 holds a TIFF header at 10, type 1 an IFD at 8, the Coolpix type 2 has
+/// no signature. ShotInfo, ColorBalance and LensData are encrypted, see `nikon`
+struct Nikon;
+
+impl MakerNoteParser for Nikon {
+    fn matches(&self, make: Option<&str>, header: &[u8]) -> bool {
+        header.starts_with(b"Nikon\0") || has_make(make, "NIKON")
+    }
+    fn layout(&self, header: &[u8], is_le: bool) -> Layout {
+        match header {
+            [b'N', b'i', b'k', b'o', b'n', 0, 2, ..] => {
+                let is_le = byte_order(header, 10).unwrap_or(is_le);
+                let ifd_offset = u32_at(header, 14, is_le).unwrap_or(8) as u64;
+                Layout::Ifd {
+                    ifd_offset: 10 + ifd_offset,
+                    is_le: Some(is_le),
+                    base: OffsetBase::MakerNote(10),
+                }
+            }
+            [b'N', b'i', b'k', b'o', b'n', 0, ..] => Layout::after_signature(8),
+            _ => Layout::after_signature(0),
+        }
+    }
+    fn decrypt(&self, entries: &mut [IFDItem], model: Option<&str>) {
+        nikon::decrypt_entries(entries, model);
+    }
+}
+
+/// `OLYMPUS\0II` and `OM SYSTEM\0\0\0II` are relative to the makernote and carry
+/// their byte order, the older `OLYMP\0` is not
+struct Olympus;
+
+impl MakerNoteParser for Olympus {
+    fn matches(&self, _: Option<&str>, header: &[u8]) -> bool {
+        header.starts_with(b"OLYMP") || header.starts_with(b"OM SYSTEM\0")
+    }
+    fn layout(&self, header: &[u8], is_le: bool) -> Layout {
+        let (order_pos, ifd_offset) = if header.starts_with(b"OLYMPUS\0") {
+            (8, 12)
+        } else if header.starts_with(b"OM SYSTEM\0") {
+            (12, 16)
+        } else {
+            return Layout::after_signature(8);
+        };
+        Layout::Ifd {
+            ifd_offset,
+            is_le: Some(byte_order(header, order_pos).unwrap_or(is_le)),
+            base: OffsetBase::MakerNote(0),
+        }
+    }
+}
+
+struct Panasonic;
+
+impl MakerNoteParser for Panasonic {
+    fn matches(&self, _: Option<&str>, header: &[u8]) -> bool {
+        header.starts_with(b"Panasonic\0")
+    }
+    fn layout(&self, _: &[u8], _: bool) -> Layout {
+        Layout::after_signature(12)
+    }
+}
+
+/// Always little endian, the IFD offset follows the signature
+struct Fujifilm;
+
+impl MakerNoteParser for Fujifilm {
+    fn matches(&self, _: Option<&str>, header: &[u8]) -> bool {
+        header.starts_with(b"FUJIFILM")
+    }
+    fn layout(&self, header: &[u8], _: bool) -> Layout {
+        Layout::Ifd {
+            ifd_offset: u32_at(header, 8, true).unwrap_or(12) as u64,
+            is_le: Some(true),
+            base: OffsetBase::MakerNote(0),
+        }
+    }
+}
+
+/// `AOC\0` uses the offsets of the file, `PENTAX \0` is relative to the makernote
+struct Pentax;
+
+impl MakerNoteParser for Pentax {
+    fn matches(&self, _: Option<&str>, header: &[u8]) -> bool {
+        header.starts_with(b"AOC\0") || header.starts_with(b"PENTAX \0")
+    }
+    fn layout(&self, header: &[u8], _: bool) -> Layout {
+        if header.starts_with(b"AOC\0") {
+            Layout::Ifd {
+                ifd_offset: 6,
+                is_le: byte_order(header, 4),
+                base: OffsetBase::Parent,
+            }
+        } else {
+            Layout::Ifd {
+                ifd_offset: 10,
+                is_le: byte_order(header, 8),
+                base: OffsetBase::MakerNote(0),
+            }
+        }
+    }
+}
+
+/// `SONY DSC \0\0\0` and the like, or no signature. Some tags are enciphered, see `sony`
+struct Sony;
+
+impl MakerNoteParser for Sony {
+    fn matches(&self, make: Option<&str>, header: &[u8]) -> bool {
+        header.starts_with(b"SONY ") || has_make(make, "SONY")
+    }
+    fn layout(&self, header: &[u8], _: bool) -> Layout {
+        if header.starts_with(b"SONY ") {
+            Layout::after_signature(12)
+        } else {
+            Layout::after_signature(0)
+        }
+    }
+    fn decipher(&self, tag: u16, data: &mut [u8]) {
+        if sony::is_enciphered(tag) {
+            sony::decipher(data);
+        }
+    }
+}
+
+struct Apple;
+
+impl MakerNoteParser for Apple {
+    fn matches(&self, _: Option<&str>, header: &[u8]) -> bool {
+        header.starts_with(b"Apple iOS\0")
+    }
+    fn layout(&self, header: &[u8], _: bool) -> Layout {
+        Layout::Ifd {
+            ifd_offset: 14,
+            is_le: Some(byte_order(header, 12).unwrap_or(false)),
+            base: OffsetBase::MakerNote(0),
+        }
+    }
+}
+
+/// A fixed binary record, not an IFD
+struct Kodak;
+
+impl MakerNoteParser for Kodak {
+    fn matches(&self, _: Option<&str>, header: &[u8]) -> bool {
+        header.starts_with(b"KDK")
+    }
+    fn layout(&self, _: &[u8], _: bool) -> Layout {
+        Layout::Opaque
+    }
+}
+
+/// No signature, the offsets are the ones of the file
+struct Canon;
+
+impl MakerNoteParser for Canon {
+    fn matches(&self, make: Option<&str>, _: &[u8]) -> bool {
+        has_make(make, "CANON")
+    }
+    fn layout(&self, _: &[u8], _: bool) -> Layout {
+        Layout::after_signature(0)
+    }
+}
+
+struct Samsung;
+
+impl MakerNoteParser for Samsung {
+    fn matches(&self, make: Option<&str>, _: &[u8]) -> bool {
+        has_make(make, "SAMSUNG")
+    }
+    fn layout(&self, _: &[u8], _: bool) -> Layout {
+        Layout::after_signature(0)
+    }
+}
@@ -0,0 +1,561 @@
+use std::io::{BufReader, Read, Seek, SeekFrom};
+
+use crate::{open_any, BlockKind, IFDItem, IFDNode, TagQuery, ToReport};
+use erreport::Report;
+
+/// Canon's 1/32 EV units, where 0x0c and 0x14 stand for 1/3 and 2/3
+fn canon_ev(x: i16) -> f64 {
+    let sign = if x < 0 { -1.0 } else { 1.0 };
+    let x = (x as i32).abs();
+    let fraction = match x & 0x1f {
+        0x0c => 32.0 / 3.0,
+        0x14 => 64.0 / 3.0,
+        y => y as f64,
+    };
+    sign * ((x & !0x1f) as f64 + fraction) / 32.0
+}
+
+fn canon_aperture(x: i16) -> f64 {
+    2f64.powf(canon_ev(x) / 2.0)
+}
+
+/// The array elements indexed like ExifTool, where 0 is the leading size word,
+/// `None` for -1, which means not available
+fn array_getter(item: Option<&IFDItem>) -> impl Fn(usize) -> Option<i16> {
+    let values = item.and_then(|x| x.i16s()).unwrap_or_default();
+    move |i| values.get(i).copied().filter(|&x| x != -1)
+}
+
+/// CameraSettings (0x0001)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CameraSettings {
+    pub macro_mode: Option<i16>,
+    /// In 1/10 seconds, 0 when off
+    pub self_timer: Option<i16>,
+    /// 1 economy, 2 normal, 3 fine, 4 RAW, 5 superfine, 130 normal movie
+    pub quality: Option<i16>,
+    pub flash_mode: Option<i16>,
+    pub continuous_drive: Option<i16>,
+    pub focus_mode: Option<i16>,
+    pub record_mode: Option<i16>,
+    pub image_size: Option<i16>,
+    pub easy_mode: Option<i16>,
+    pub metering_mode: Option<i16>,
+    pub focus_range: Option<i16>,
+    pub exposure_mode: Option<i16>,
+    /// The lens id, as listed by ExifTool's CanonLensTypes
+    pub lens_type: Option<u16>,
+    /// In mm
+    pub max_focal_length: Option<f64>,
+    pub min_focal_length: Option<f64>,
+    /// As f-numbers
+    pub max_aperture: Option<f64>,
+    pub min_aperture: Option<f64>,
+    pub image_stabilization: Option<i16>,
+}
+
+impl CameraSettings {
+    pub fn decode(item: &IFDItem) -> Self {
+        let get = array_getter(Some(item));
+        let focal_units = get(25).filter(|&x| x > 0).unwrap_or(1) as f64;
+        let focal_length = |i| get(i).filter(|&x| x > 0).map(|x| x as u16 as f64 / focal_units);
+        let aperture = |i| get(i).filter(|&x| x > 0).map(canon_aperture);
+
+        Self {
+            macro_mode: get(1),
+            self_timer: get(2),
+            quality: get(3),
+            flash_mode: get(4),
+            continuous_drive: get(5),
+            focus_mode: get(7),
+            record_mode: get(9),
+            image_size: get(10),
+            easy_mode: get(11),
+            metering_mode: get(17),
+            focus_range: get(18),
+            exposure_mode: get(20),
+            lens_type: get(22).map(|x| x as u16),
+            max_focal_length: focal_length(23),
+            min_focal_length: focal_length(24),
+            max_aperture: aperture(26),
+            min_aperture: aperture(27),
+            image_stabilization: get(34),
+        }
+    }
+}
+
+/// ShotInfo (0x0004)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ShotInfo {
+    /// In percent of `base_iso`
+    pub auto_iso: Option<f64>,
+    pub base_iso: Option<f64>,
+    /// In EV
+    pub exposure_compensation: Option<f64>,
+    pub white_balance: Option<i16>,
+    pub sequence_number: Option<i16>,
+    /// In degrees Celsius
+    pub camera_temperature: Option<i16>,
+    pub flash_exposure_compensation: Option<f64>,
+    pub f_number: Option<f64>,
+    /// In seconds
+    pub exposure_time: Option<f64>,
+    /// In meters, `f64::INFINITY` for infinity
+    pub focus_distance_upper: Option<f64>,
+    pub focus_distance_lower: Option<f64>,
+    pub bulb_duration: Option<i16>,
+    pub nd_filter: Option<i16>,
+}
+
+impl ShotInfo {
+    pub fn decode(item: &IFDItem) -> Self {
+        let get = array_getter(Some(item));
+        let values = item.i16s().unwrap_or_default();
+        let raw = |i: usize| values.get(i).copied();
+        let distance = |i| {
+            raw(i).filter(|&x| x != 0).map(|x| match x as u16 {
+                0xffff => f64::INFINITY,
+                x => x as f64 / 100.0,
+            })
+        };
+
+        Self {
+            auto_iso: raw(1).map(|x| (x as f64 / 32.0).exp2() * 100.0),
+            base_iso: get(2)
+                .filter(|&x| x != 0)
+                .map(|x| (x as f64 / 32.0).exp2() * 100.0 / 32.0),
+            exposure_compensation: raw(6).map(canon_ev),
+            white_balance: get(7),
+            sequence_number: get(9),
+            camera_temperature: get(12).filter(|&x| x != 0).map(|x| x - 128),
+            flash_exposure_compensation: raw(15).map(canon_ev),
+            f_number: get(21).filter(|&x| x != 0).map(canon_aperture),
+            exposure_time: get(22).filter(|&x| x != 0).map(|x| (-canon_ev(x)).exp2()),
+            focus_distance_upper: distance(19),
+            focus_distance_lower: distance(20),
+            bulb_duration: get(24),
+            nd_filter: get(28),
+        }
+    }
+
+    /// `base_iso` scaled by `auto_iso`
+    pub fn iso(&self) -> Option<f64> {
+        Some(self.base_iso? * self.auto_iso.unwrap_or(100.0) / 100.0)
+    }
+}
+
+/// A white balance preset of ColorData
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WbPreset {
+    /// Like `Daylight`
+    pub name: &'static str,
+    /// RGGB
+    pub levels: [u16; 4],
+    /// In kelvin
+    pub color_temperature: u16,
+}
+
+/// ColorData (0x4001), whose layout is identified by its element count
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColorData {
+    /// 1 to 12, following ExifTool's ColorData1 .. ColorData12
+    pub version: u8,
+    /// RGGB levels of the as-shot white balance
+    pub as_shot: [u16; 4],
+    /// Only decoded for the versions 3, 4 and 6 to 12, the as-shot one comes first
+    pub presets: Vec<WbPreset>,
+    /// RGGB, the levels are only known for the versions 4 and 6 to 11
+    pub black_levels: Option<[u16; 4]>,
+    pub white_level: Option<u16>,
+    pub specular_white_level: Option<u16>,
+    pub linearity_upper_margin: Option<u16>,
+}
+
+/// The (name, offset) of the 5 elements presets in ColorData3, following ExifTool
+const PRESETS3: [(&str, usize); 10] = [
+    ("AsShot", 0x3f),
+    ("Auto", 0x44),
+    ("Measured", 0x49),
+    ("Daylight", 0x4e),
+    ("Shade", 0x53),
+    ("Cloudy", 0x58),
+    ("Tungsten", 0x5d),
+    ("Fluorescent", 0x62),
+    ("Kelvin", 0x67),
+    ("Flash", 0x6c),
+];
+
+/// ColorData4 has an unknown preset at 0x4e
+const PRESETS4: [(&str, usize); 10] = [
+    ("AsShot", 0x3f),
+    ("Auto", 0x44),
+    ("Measured", 0x49),
+    ("Daylight", 0x53),
+    ("Shade", 0x58),
+    ("Cloudy", 0x5d),
+    ("Tungsten", 0x62),
+    ("Fluorescent", 0x67),
+    ("Kelvin", 0x6c),
+    ("Flash", 0x71),
+];
+
+/// ColorData6 has five unknown presets from 0x4e
+const PRESETS6: [(&str, usize); 10] = [
+    ("AsShot", 0x3f),
+    ("Auto", 0x44),
+    ("Measured", 0x49),
+    ("Daylight", 0x67),
+    ("Shade", 0x6c),
+    ("Cloudy", 0x71),
+    ("Tungsten", 0x76),
+    ("Fluorescent", 0x7b),
+    ("Kelvin", 0x80),
+    ("Flash", 0x85),
+];
+
+/// ColorData7 and ColorData8 have ten unknown presets from 0x4e
+const PRESETS7: [(&str, usize); 10] = [
+    ("AsShot", 0x3f),
+    ("Auto", 0x44),
+    ("Measured", 0x49),
+    ("Daylight", 0x80),
+    ("Shade", 0x85),
+    ("Cloudy", 0x8a),
+    ("Tungsten", 0x8f),
+    ("Fluorescent", 0x94),
+    ("Kelvin", 0x99),
+    ("Flash", 0x9e),
+];
+
+/// ColorData9 moves the presets of ColorData8 by 8
+const PRESETS9: [(&str, usize); 10] = [
+    ("AsShot", 0x47),
+    ("Auto", 0x4c),
+    ("Measured", 0x51),
+    ("Daylight", 0x88),
+    ("Shade", 0x8d),
+    ("Cloudy", 0x92),
+    ("Tungsten", 0x97),
+    ("Fluorescent", 0x9c),
+    ("Kelvin", 0xa1),
+    ("Flash", 0xa6),
+];
+
+const PRESETS10: [(&str, usize); 10] = [
+    ("AsShot", 0x55),
+    ("Auto", 0x5a),
+    ("Measured", 0x5f),
+    ("Daylight", 0x96),
+    ("Shade", 0x9b),
+    ("Cloudy", 0xa0),
+    ("Tungsten", 0xa5),
+    ("Fluorescent", 0xaa),
+    ("Kelvin", 0xaf),
+    ("Flash", 0xb4),
+];
+
+/// ColorData11 and ColorData12
+const PRESETS11: [(&str, usize); 10] = [
+    ("AsShot", 0x69),
+    ("Auto", 0x6e),
+    ("Measured", 0x73),
+    ("Daylight", 0xaa),
+    ("Shade", 0xaf),
+    ("Cloudy", 0xb4),
+    ("Tungsten", 0xb9),
+    ("Fluorescent", 0xbe),
+    ("Kelvin", 0xc3),
+    ("Flash", 0xc8),
+];
+
+impl ColorData {
+    pub fn decode(item: &IFDItem) -> Option<Self> {
+        let values = item.u16s()?;
+        let version = match values.len() {
+            582 => 1,
+            653 => 2,
+            796 => 3,
+            674 | 692 | 702 | 1227 | 1250 | 1251 | 1337 | 1338 | 1346 => 4,
+            5120 => 5,
+            1273 | 1275 => 6,
+            1312 | 1313 | 1316 | 1506 => 7,
+            1560 | 1592 | 1353 | 1602 => 8,
+            1816 | 1820 | 1824 => 9,
+            2024 | 3656 => 10,
+            3973 | 3778 => 11,
+            4528 => 12,
+            _ => return None,
+        };
+        let as_shot = match version {
+            1 => 0x19,
+            2 => 0x18,
+            5 | 9 => 0x47,
+            10 => 0x55,
+            11 | 12 => 0x69,
+            _ => 0x3f,
+        };
+        let levels = |i: usize| -> Option<[u16; 4]> { values.get(i..i + 4)?.try_into().ok() };
+
+        let presets: &[(&str, usize)] = match version {
+            3 => &PRESETS3,
+            4 => &PRESETS4,
+            6 => &PRESETS6,
+            7 | 8 => &PRESETS7,
+            9 => &PRESETS9,
+            10 => &PRESETS10,
+            11 | 12 => &PRESETS11,
+            _ => &[],
+        };
+        let presets = presets
+            .iter()
+            .filter_map(|&(name, start)| {
+                Some(WbPreset {
+                    name,
+                    levels: levels(start)?,
+                    color_temperature: *values.get(start + 4)?,
+                })
+            })
+            .collect();
+        // PerChannelBlackLevel, NormalWhiteLevel, SpecularWhiteLevel and LinearityUpperMargin
+        let (black, white, specular, linearity) = match version {
+            4 => (0x2b4, 0x2b8, 0x2b9, 0x2ba),
+            6 => (0x108, 0x27c, 0x27d, 0x27e),
+            7 => (0x114, 0x2d8, 0x2d9, 0x2da),
+            8 => (0x42f, 0x433, 0x434, 0x435),
+            9 => (0x467, 0x46b, 0x46c, 0x46d),
+            10 => (0x549, 0x54d, 0x54e, 0x54f),
+            11 => (0x569, 0x56d, 0x56e, 0x56f),
+            _ => (0, 0, 0, 0),
+        };
+        let level = |i: usize| (i > 0).then(|| values.get(i).copied()).flatten();
+
+        Some(Self {
+            version,
+            as_shot: levels(as_shot)?,
+            presets,
+            black_levels: (black > 0).then(|| levels(black)).flatten(),
+            white_level: level(white),
+            specular_white_level: level(specular),
+            linearity_upper_margin: level(linearity),
+        })
+    }
+
+    /// The as-shot red and blue multipliers relative to the average of the greens
+    pub fn wb_multipliers(&self) -> Option<[f64; 3]> {
+        let [r, g1, g2, b] = self.as_shot.map(|x| x as f64);
+        let g = (g1 + g2) / 2.0;
+        (r > 0.0 && g > 0.0 && b > 0.0).then(|| [g / r, 1.0, g / b])
+    }
+}
+
+/// An AF point of AFInfo2, the positions are relative to the image center
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AfPoint {
+    pub width: u16,
+    pub height: u16,
+    pub x: i16,
+    pub y: i16,
+    pub in_focus: bool,
+    pub selected: bool,
+}
+
+/// AFInfo2 (0x0026)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AfInfo {
+    pub area_mode: u16,
+    pub valid_points: u16,
+    pub image_width: u16,
+    pub image_height: u16,
+    /// The size the AF point positions are measured in
+    pub af_image_width: u16,
+    pub af_image_height: u16,
+    pub points: Vec<AfPoint>,
+}
+
+impl AfInfo {
+    pub fn decode(item: &IFDItem) -> Option<Self> {
+        let values = item.u16s()?;
+        let count = *values.get(2)? as usize;
+        let mask_len = count.div_ceil(16);
+        // widths, heights, x and y positions, then the in focus and selected bit masks
+        let needed = 8 + count * 4 + mask_len * 2;
+        if values.len() < needed {
+            return None;
+        }
+        let bit = |start: usize, i: usize| values[start + i / 16] & (1 << (i % 16)) != 0;
+        let (in_focus, selected) = (8 + count * 4, 8 + count * 4 + mask_len);
+
+        let points = (0..count)
+            .map(|i| AfPoint {
+                width: values[8 + i],
+                height: values[8 + count + i],
+                x: values[8 + count * 2 + i] as i16,
+                y: values[8 + count * 3 + i] as i16,
+                in_focus: bit(in_focus, i),
+                selected: bit(selected, i),
+            })
+            .collect();
+
+        Some(Self {
+            area_mode: values[1],
+            valid_points: values[3],
+            image_width: values[4],
+            image_height: values[5],
+            af_image_width: values[6],
+            af_image_height: values[7],
+            points,
+        })
+    }
+}
+
+/// FileInfo (0x0093)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FileInfo {
+    /// The file number or the shutter count, depending on the model
+    pub file_number: Option<u32>,
+    pub bracket_mode: Option<i16>,
+    pub bracket_value: Option<i16>,
+    pub bracket_shot_number: Option<i16>,
+    pub raw_jpg_quality: Option<i16>,
+    pub raw_jpg_size: Option<i16>,
+    pub long_exposure_noise_reduction: Option<i16>,
+    pub filter_effect: Option<i16>,
+    pub toning_effect: Option<i16>,
+    pub live_view_shooting: Option<bool>,
+}
+
+impl FileInfo {
+    pub fn decode(item: &IFDItem) -> Self {
+        let get = array_getter(Some(item));
+        let data = item.data();
+        // an int32u spanning the elements 1 and 2
+        let file_number = data.get(2..6).map(|x| {
+            let x = [x[0], x[1], x[2], x[3]];
+            if item.is_le {
+                u32::from_le_bytes(x)
+            } else {
+                u32::from_be_bytes(x)
+            }
+        });
+
+        Self {
+            file_number,
+            bracket_mode: get(3),
+            bracket_value: get(4),
+            bracket_shot_number: get(5),
+            raw_jpg_quality: get(6),
+            raw_jpg_size: get(7),
+            long_exposure_noise_reduction: get(8),
+            filter_effect: get(14),
+            toning_effect: get(15),
+            live_view_shooting: get(19).map(|x| x == 1),
+        }
+    }
+}
+
+/// SensorInfo (0x00e0), the borders are inclusive pixel positions
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SensorInfo {
+    pub width: u16,
+    pub height: u16,
+    /// Left, top, right and bottom of the image area
+    pub borders: [u16; 4],
+    /// Left, top, right and bottom of the masked area used for the black level
+    pub black_mask_borders: [u16; 4],
+}
+
+impl SensorInfo {
+    pub fn decode(item: &IFDItem) -> Option<Self> {
+        let values = item.u16s()?;
+        let get = |i: usize| values.get(i).copied();
+        let borders = |i: usize| -> Option<[u16; 4]> { values.get(i..i + 4)?.try_into().ok() };
+        Some(Self {
+            width: get(1)?,
+            height: get(2)?,
+            borders: borders(5)?,
+            black_mask_borders: borders(9)?,
+        })
+    }
+}
+
+/// The decoded Canon makernote
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CanonMakerNote {
+    pub camera_settings: Option<CameraSettings>,
+    pub shot_info: Option<ShotInfo>,
+    pub model_id: Option<u32>,
+    pub lens_model: Option<String>,
+    pub color_data: Option<ColorData>,
+    pub af_info: Option<AfInfo>,
+    pub file_info: Option<FileInfo>,
+    pub sensor_info: Option<SensorInfo>,
+}
+
+impl CanonMakerNote {
+    /// Decodes the entries returned by `get`, which looks a tag up in the makernote IFD
+    pub fn decode<'a>(get: impl Fn(u16) -> Option<&'a IFDItem>) -> Self {
+        Self {
+            camera_settings: get(0x0001).map(CameraSettings::decode),
+            shot_info: get(0x0004).map(ShotInfo::decode),
+            model_id: get(0x0010).and_then(|x| x.u32s()).map(|x| x[0]),
+            lens_model: get(0x0095).and_then(|x| x.text()).map(|x| x.text),
+            color_data: get(0x4001).and_then(ColorData::decode),
+            af_info: get(0x0026).and_then(AfInfo::decode),
+            file_info: get(0x0093).map(FileInfo::decode),
+            sensor_info: get(0x00e0).and_then(SensorInfo::decode),
+        }
+    }
+
+    /// Decodes a makernote node of `parse_exif_tree`
+    pub fn from_node(node: &IFDNode) -> Self {
+        Self::decode(|tag| node.get(tag))
+    }
+
+    /// The lens id of CameraSettings
+    pub fn lens_type(&self) -> Option<u16> {
+        self.camera_settings.as_ref()?.lens_type
+    }
+
+    /// Reads the makernote of a CR2, JPEG or CR3 file, merging CR3's CMT3 with the
+    /// makernote blocks of its CTMD track. The first block holding a field wins.
+    ///
+    /// The reader is restored to its current position afterwards.
+    pub fn read<T: Read + Seek>(reader: &mut BufReader<T>) -> Result<Self, Report> {
+        let start = reader.stream_position().to_report()?;
+        let (_, blocks) = open_any(reader).to_report()?;
+
+        let mut result = Self::default();
+        for block in blocks {
+            let path: &[u16] = match block.kind {
+                BlockKind::Tiff => &[0, 0x8769, 0, 0x927c, 0],
+                BlockKind::MakerNote => &[0],
+                _ => continue,
+            };
+            let mut query = TagQuery::new();
+            let path_index = query.add_path(path);
+            if block.kind == BlockKind::MakerNote {
+                query.set_makernote_make("Canon");
+            }
+            block.seek(reader).to_report()?;
+            // a broken block only loses its fields
+            let Ok(data) = query.parse(BufReader::new(&mut *reader)) else {
+                continue;
+            };
+            result.merge(Self::decode(|tag| data.get_key(&(path_index, tag))));
+        }
+
+        reader.seek(SeekFrom::Start(start)).to_report()?;
+        Ok(result)
+    }
+
+    fn merge(&mut self, other: Self) {
+        macro_rules! merge {
+            ($($field:ident)*) => {
+                $(if self.$field.is_none() {
+                    self.$field = other.$field;
+                })*
+            };
+        }
+        merge!(camera_settings shot_info model_id lens_model color_data af_info file_info sensor_info);
+    }
+}
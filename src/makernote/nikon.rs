@@ -0,0 +1,336 @@
+use std::io::{BufReader, Read, Seek, SeekFrom};
+
+use crate::{open_any, BlockKind, IFDItem, IFDNode, TagQuery, ToReport};
+use erreport::Report;
+
+/// The substitution tables of the encrypted records, from dcraw
+const XLAT: [[u8; 256]; 2] = [
+    [
+        0xc1, 0xbf, 0x6d, 0x0d, 0x59, 0xc5, 0x13, 0x9d, 0x83, 0x61, 0x6b, 0x4f, 0xc7, 0x7f, 0x3d, 0x3d,
+        0x53, 0x59, 0xe3, 0xc7, 0xe9, 0x2f, 0x95, 0xa7, 0x95, 0x1f, 0xdf, 0x7f, 0x2b, 0x29, 0xc7, 0x0d,
+        0xdf, 0x07, 0xef, 0x71, 0x89, 0x3d, 0x13, 0x3d, 0x3b, 0x13, 0xfb, 0x0d, 0x89, 0xc1, 0x65, 0x1f,
+        0xb3, 0x0d, 0x6b, 0x29, 0xe3, 0xfb, 0xef, 0xa3, 0x6b, 0x47, 0x7f, 0x95, 0x35, 0xa7, 0x47, 0x4f,
+        0xc7, 0xf1, 0x59, 0x95, 0x35, 0x11, 0x29, 0x61, 0xf1, 0x3d, 0xb3, 0x2b, 0x0d, 0x43, 0x89, 0xc1,
+        0x9d, 0x9d, 0x89, 0x65, 0xf1, 0xe9, 0xdf, 0xbf, 0x3d, 0x7f, 0x53, 0x97, 0xe5, 0xe9, 0x95, 0x17,
+        0x1d, 0x3d, 0x8b, 0xfb, 0xc7, 0xe3, 0x67, 0xa7, 0x07, 0xf1, 0x71, 0xa7, 0x53, 0xb5, 0x29, 0x89,
+        0xe5, 0x2b, 0xa7, 0x17, 0x29, 0xe9, 0x4f, 0xc5, 0x65, 0x6d, 0x6b, 0xef, 0x0d, 0x89, 0x49, 0x2f,
+        0xb3, 0x43, 0x53, 0x65, 0x1d, 0x49, 0xa3, 0x13, 0x89, 0x59, 0xef, 0x6b, 0xef, 0x65, 0x1d, 0x0b,
+        0x59, 0x13, 0xe3, 0x4f, 0x9d, 0xb3, 0x29, 0x43, 0x2b, 0x07, 0x1d, 0x95, 0x59, 0x59, 0x47, 0xfb,
+        0xe5, 0xe9, 0x61, 0x47, 0x2f, 0x35, 0x7f, 0x17, 0x7f, 0xef, 0x7f, 0x95, 0x95, 0x71, 0xd3, 0xa3,
+        0x0b, 0x71, 0xa3, 0xad, 0x0b, 0x3b, 0xb5, 0xfb, 0xa3, 0xbf, 0x4f, 0x83, 0x1d, 0xad, 0xe9, 0x2f,
+        0x71, 0x65, 0xa3, 0xe5, 0x07, 0x35, 0x3d, 0x0d, 0xb5, 0xe9, 0xe5, 0x47, 0x3b, 0x9d, 0xef, 0x35,
+        0xa3, 0xbf, 0xb3, 0xdf, 0x53, 0xd3, 0x97, 0x53, 0x49, 0x71, 0x07, 0x35, 0x61, 0x71, 0x2f, 0x43,
+        0x2f, 0x11, 0xdf, 0x17, 0x97, 0xfb, 0x95, 0x3b, 0x7f, 0x6b, 0xd3, 0x25, 0xbf, 0xad, 0xc7, 0xc5,
+        0xc5, 0xb5, 0x8b, 0xef, 0x2f, 0xd3, 0x07, 0x6b, 0x25, 0x49, 0x95, 0x25, 0x49, 0x6d, 0x71, 0xc7,
+    ],
+    [
+        0xa7, 0xbc, 0xc9, 0xad, 0x91, 0xdf, 0x85, 0xe5, 0xd4, 0x78, 0xd5, 0x17, 0x46, 0x7c, 0x29, 0x4c,
+        0x4d, 0x03, 0xe9, 0x25, 0x68, 0x11, 0x86, 0xb3, 0xbd, 0xf7, 0x6f, 0x61, 0x22, 0xa2, 0x26, 0x34,
+        0x2a, 0xbe, 0x1e, 0x46, 0x14, 0x68, 0x9d, 0x44, 0x18, 0xc2, 0x40, 0xf4, 0x7e, 0x5f, 0x1b, 0xad,
+        0x0b, 0x94, 0xb6, 0x67, 0xb4, 0x0b, 0xe1, 0xea, 0x95, 0x9c, 0x66, 0xdc, 0xe7, 0x5d, 0x6c, 0x05,
+        0xda, 0xd5, 0xdf, 0x7a, 0xef, 0xf6, 0xdb, 0x1f, 0x82, 0x4c, 0xc0, 0x68, 0x47, 0xa1, 0xbd, 0xee,
+        0x39, 0x50, 0x56, 0x4a, 0xdd, 0xdf, 0xa5, 0xf8, 0xc6, 0xda, 0xca, 0x90, 0xca, 0x01, 0x42, 0x9d,
+        0x8b, 0x0c, 0x73, 0x43, 0x75, 0x05, 0x94, 0xde, 0x24, 0xb3, 0x80, 0x34, 0xe5, 0x2c, 0xdc, 0x9b,
+        0x3f, 0xca, 0x33, 0x45, 0xd0, 0xdb, 0x5f, 0xf5, 0x52, 0xc3, 0x21, 0xda, 0xe2, 0x22, 0x72, 0x6b,
+        0x3e, 0xd0, 0x5b, 0xa8, 0x87, 0x8c, 0x06, 0x5d, 0x0f, 0xdd, 0x09, 0x19, 0x93, 0xd0, 0xb9, 0xfc,
+        0x8b, 0x0f, 0x84, 0x60, 0x33, 0x1c, 0x9b, 0x45, 0xf1, 0xf0, 0xa3, 0x94, 0x3a, 0x12, 0x77, 0x33,
+        0x4d, 0x44, 0x78, 0x28, 0x3c, 0x9e, 0xfd, 0x65, 0x57, 0x16, 0x94, 0x6b, 0xfb, 0x59, 0xd0, 0xc8,
+        0x22, 0x36, 0xdb, 0xd2, 0x63, 0x98, 0x43, 0xa1, 0x04, 0x87, 0x86, 0xf7, 0xa6, 0x26, 0xbb, 0xd6,
+        0x59, 0x4d, 0xbf, 0x6a, 0x2e, 0xaa, 0x2b, 0xef, 0xe6, 0x78, 0xb6, 0x4e, 0xe0, 0x2f, 0xdc, 0x7c,
+        0xbe, 0x57, 0x19, 0x32, 0x7e, 0x2a, 0xd0, 0xb8, 0xba, 0x29, 0x00, 0x3c, 0x52, 0x7d, 0xa8, 0x49,
+        0x3b, 0x2d, 0xeb, 0x25, 0x49, 0xfa, 0xa3, 0xaa, 0x39, 0xa7, 0xc5, 0xa7, 0x50, 0x11, 0x36, 0xfb,
+        0xc6, 0x67, 0x4a, 0xf5, 0xa5, 0x12, 0x65, 0x7e, 0xb0, 0xdf, 0xaf, 0x4e, 0xb3, 0x61, 0x7f, 0x2f,
+    ],
+];
+
+/// The serial number part of the key: the SerialNumber tag (0x001d) when it is a number,
+/// otherwise 0x22 for the D50 and 0x60 for the other models
+pub fn serial_key(serial_number: Option<&str>, model: Option<&str>) -> u32 {
+    let serial = serial_number.map(|x| x.trim_matches(|x: char| x == '\0' || x.is_whitespace()));
+    match serial.and_then(|x| x.parse().ok()) {
+        Some(x) => x,
+        None if model.is_some_and(|x| x.trim_end().ends_with("D50")) => 0x22,
+        None => 0x60,
+    }
+}
+
+/// Deciphers `data` in place, the key stream starts at its first byte.
+///
+/// `serial` is given by `serial_key`, `shutter_count` is the ShutterCount tag (0x00a7).
+/// Makernotes parsed by this crate are already deciphered, this is for values read
+/// by other means.
+pub fn decrypt(data: &mut [u8], serial: u32, shutter_count: u32) {
+    let count = shutter_count.to_le_bytes();
+    let ci = XLAT[0][(serial & 0xff) as usize];
+    let mut cj = XLAT[1][(count[0] ^ count[1] ^ count[2] ^ count[3]) as usize];
+    let mut ck = 0x60u8;
+    for x in data.iter_mut() {
+        cj = cj.wrapping_add(ci.wrapping_mul(ck));
+        ck = ck.wrapping_add(1);
+        *x ^= cj;
+    }
+}
+
+/// The 4 ASCII digits leading the versioned records, like `0204`
+fn version(data: &[u8]) -> Option<String> {
+    let x = data.get(..4)?;
+    x.iter()
+        .all(|x| x.is_ascii_digit())
+        .then(|| String::from_utf8_lossy(x).into_owned())
+}
+
+/// Where the encryption of a record starts, `None` when the record is not encrypted
+fn encrypted_from(tag: u16, version: &str) -> Option<usize> {
+    let number: u16 = version.parse().ok()?;
+    match (tag, number) {
+        (0x0091, 200..) => Some(4),
+        (0x0097, 205) => Some(4),
+        (0x0097, 200..=216) => Some(284),
+        (0x0098, 201..=204 | 400..=403 | 800..=801) => Some(4),
+        _ => None,
+    }
+}
+
+/// Deciphers ShotInfo, ColorBalance and LensData among the entries of a makernote IFD,
+/// they stay encrypted without SerialNumber and ShutterCount
+pub(crate) fn decrypt_entries(entries: &mut [IFDItem], model: Option<&str>) {
+    let get = |tag| entries.iter().find(|x| x.tag == tag);
+    let serial_number = get(0x001d).and_then(|x| x.text()).map(|x| x.text);
+    let Some(shutter_count) = get(0x00a7).and_then(|x| x.u32s()).map(|x| x[0]) else {
+        return;
+    };
+    let key = Key {
+        serial: serial_key(serial_number.as_deref(), model),
+        shutter_count,
+    };
+
+    for item in entries.iter_mut() {
+        let tag = item.tag;
+        let Some(data) = item.actual_value.as_deref_mut() else {
+            continue;
+        };
+        if let Some(start) = version(data).and_then(|x| encrypted_from(tag, &x)) {
+            key.decrypt(data, start);
+        }
+    }
+}
+
+/// The key of the encrypted records
+#[derive(Debug, Clone, Copy)]
+struct Key {
+    serial: u32,
+    shutter_count: u32,
+}
+
+impl Key {
+    /// Deciphers the record from `start` to the end
+    fn decrypt(&self, data: &mut [u8], start: usize) {
+        if let Some(x) = data.get_mut(start..) {
+            decrypt(x, self.serial, self.shutter_count);
+        }
+    }
+}
+
+/// ColorBalance (0x0097)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColorBalance {
+    pub version: String,
+    /// RGGB levels of the as-shot white balance
+    pub wb_levels: [u16; 4],
+}
+
+impl ColorBalance {
+    /// Decodes a deciphered value, like dcraw only the versions up to 0216 are known
+    pub fn decode(item: &IFDItem) -> Option<Self> {
+        let data = item.data();
+        let version = version(data)?;
+        let number: u16 = version.parse().ok()?;
+        let u16_at = |data: &[u8], i: usize| -> Option<u16> {
+            let x = [*data.get(i)?, *data.get(i + 1)?];
+            Some(if item.is_le {
+                u16::from_le_bytes(x)
+            } else {
+                u16::from_be_bytes(x)
+            })
+        };
+        let levels = |data: &[u8], start: usize| -> Option<[u16; 4]> {
+            Some([
+                u16_at(data, start)?,
+                u16_at(data, start + 2)?,
+                u16_at(data, start + 4)?,
+                u16_at(data, start + 6)?,
+            ])
+        };
+
+        let wb_levels = match number {
+            // stored as RBGG, RGGB and RGBG
+            100 => levels(data, 72).map(|[r, b, g1, g2]| [r, g1, g2, b])?,
+            102 => levels(data, 10)?,
+            103 => levels(data, 20).map(|[r, g1, b, g2]| [r, g1, g2, b])?,
+            200..=216 => {
+                let start = if number == 205 { 4 } else { 284 };
+                let i = b"66666>666;6A;:;55"[(number - 200) as usize] as usize - b'0' as usize;
+                let x = levels(data, start + (i & !1))?;
+                // RGGB at even positions, GRBG at odd ones
+                if i & 1 == 0 {
+                    x
+                } else {
+                    let [g1, r, b, g2] = x;
+                    [r, g1, g2, b]
+                }
+            }
+            _ => return None,
+        };
+        Some(Self { version, wb_levels })
+    }
+
+    /// The red and blue multipliers relative to the average of the greens
+    pub fn wb_multipliers(&self) -> Option<[f64; 3]> {
+        let [r, g1, g2, b] = self.wb_levels.map(|x| x as f64);
+        let g = (g1 + g2) / 2.0;
+        (r > 0.0 && g > 0.0 && b > 0.0).then(|| [g / r, 1.0, g / b])
+    }
+}
+
+/// LensData (0x0098), the versions 0100 to 0204.
+///
+/// The versions 0400 to 0403 and 0800 to 0801 of the recent bodies are deciphered in
+/// the parser, but their layout is not decoded and `decode` gives `None` for them.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LensData {
+    pub version: String,
+    /// The first byte of the lens id, which ExifTool's lens table is keyed by along
+    /// with the other raw lens bytes, see `lens_id`
+    pub lens_id_number: Option<u8>,
+    pub f_stops: Option<f64>,
+    /// In mm
+    pub min_focal_length: Option<f64>,
+    pub max_focal_length: Option<f64>,
+    pub max_aperture_at_min_focal: Option<f64>,
+    pub max_aperture_at_max_focal: Option<f64>,
+    pub mcu_version: Option<u8>,
+    /// In mm
+    pub focal_length: Option<f64>,
+    /// In meters
+    pub focus_distance: Option<f64>,
+    pub focus_position: Option<u8>,
+    /// In mm
+    pub exit_pupil_position: Option<f64>,
+    pub af_aperture: Option<f64>,
+    pub effective_max_aperture: Option<f64>,
+    /// LensIDNumber, LensFStops, MinFocalLength, MaxFocalLength, MaxApertureAtMinFocal,
+    /// MaxApertureAtMaxFocal and MCUVersion as stored
+    pub lens_id: Option<[u8; 7]>,
+}
+
+impl LensData {
+    /// Decodes a deciphered value, `None` for the versions after 0204
+    pub fn decode(item: &IFDItem) -> Option<Self> {
+        let data = item.data();
+        let version = version(data)?;
+        // the position of LensIDNumber and the lens fields before it
+        let (id, lens_fields): (usize, &[usize; 5]) = match version.as_str() {
+            "0100" => (0x06, &[0; 5]),
+            "0101" | "0201" | "0202" | "0203" => (0x0b, &[0x04, 0x05, 0x08, 0x09, 0x0a]),
+            "0204" => (0x0c, &[0x04, 0x05, 0x08, 0x0a, 0x0b]),
+            _ => return None,
+        };
+
+        let byte = |i: usize| data.get(i).copied();
+        let nonzero = |i: usize| byte(i).filter(|&x| x != 0);
+        let exp = |x: u8| (x as f64 / 24.0).exp2();
+        let [exit_pupil, af_aperture, focus_position, focus_distance, focal_length] = *lens_fields;
+        let has_focus = version != "0100";
+
+        Some(Self {
+            lens_id_number: byte(id),
+            f_stops: byte(id + 1).map(|x| x as f64 / 12.0),
+            min_focal_length: byte(id + 2).map(|x| 5.0 * exp(x)),
+            max_focal_length: byte(id + 3).map(|x| 5.0 * exp(x)),
+            max_aperture_at_min_focal: byte(id + 4).map(exp),
+            max_aperture_at_max_focal: byte(id + 5).map(exp),
+            mcu_version: byte(id + 6),
+            focal_length: has_focus.then(|| byte(focal_length).map(|x| 5.0 * exp(x))).flatten(),
+            focus_distance: has_focus
+                .then(|| byte(focus_distance).map(|x| 0.01 * 10f64.powf(x as f64 / 40.0)))
+                .flatten(),
+            focus_position: has_focus.then(|| byte(focus_position)).flatten(),
+            exit_pupil_position: has_focus
+                .then(|| nonzero(exit_pupil).map(|x| 2048.0 / x as f64))
+                .flatten(),
+            af_aperture: has_focus.then(|| byte(af_aperture).map(exp)).flatten(),
+            effective_max_aperture: has_focus.then(|| byte(id + 7).map(exp)).flatten(),
+            lens_id: data.get(id..id + 7).and_then(|x| x.try_into().ok()),
+            version,
+        })
+    }
+}
+
+/// ShotInfo (0x0091), whose layout depends on the model
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShotInfo {
+    pub version: String,
+    /// The whole record, which the parser deciphers after the version from 0200 on
+    pub data: Box<[u8]>,
+}
+
+impl ShotInfo {
+    /// Keeps a deciphered value
+    pub fn decode(item: &IFDItem) -> Option<Self> {
+        let data = item.data();
+        Some(Self {
+            version: version(data)?,
+            data: data.into(),
+        })
+    }
+}
+
+/// The decoded records of the Nikon makernote
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NikonMakerNote {
+    pub serial_number: Option<String>,
+    pub shutter_count: Option<u32>,
+    pub shot_info: Option<ShotInfo>,
+    pub color_balance: Option<ColorBalance>,
+    pub lens_data: Option<LensData>,
+}
+
+impl NikonMakerNote {
+    /// Decodes the entries returned by `get`, which looks a tag up in the makernote IFD.
+    ///
+    /// The entries are expected to be deciphered, like the ones of `parse_exif`.
+    pub fn decode<'a>(get: impl Fn(u16) -> Option<&'a IFDItem>) -> Self {
+        Self {
+            serial_number: get(0x001d).and_then(|x| x.text()).map(|x| x.text),
+            shutter_count: get(0x00a7).and_then(|x| x.u32s()).map(|x| x[0]),
+            shot_info: get(0x0091).and_then(ShotInfo::decode),
+            color_balance: get(0x0097).and_then(ColorBalance::decode),
+            lens_data: get(0x0098).and_then(LensData::decode),
+        }
+    }
+
+    /// Decodes a makernote node of `parse_exif_tree`
+    pub fn from_node(node: &IFDNode) -> Self {
+        Self::decode(|tag| node.get(tag))
+    }
+
+    /// Reads the makernote of a NEF or JPEG file.
+    ///
+    /// The reader is restored to its current position afterwards.
+    pub fn read<T: Read + Seek>(reader: &mut BufReader<T>) -> Result<Self, Report> {
+        let start = reader.stream_position().to_report()?;
+        let (_, blocks) = open_any(reader).to_report()?;
+
+        let mut result = Self::default();
+        if let Some(block) = blocks.iter().find(|x| x.kind == BlockKind::Tiff) {
+            let mut query = TagQuery::new();
+            let path_index = query.add_path(&[0, 0x8769, 0, 0x927c, 0]);
+            block.seek(reader).to_report()?;
+            let data = query.parse(BufReader::new(&mut *reader)).to_report()?;
+            result = Self::decode(|tag| data.get_key(&(path_index, tag)));
+        }
+
+        reader.seek(SeekFrom::Start(start)).to_report()?;
+        Ok(result)
+    }
+}
@@ -0,0 +1,258 @@
+use std::io::{BufReader, Read, Seek, SeekFrom};
+
+use crate::{open_any, BlockKind, IFDItem, IFDNode, TagQuery, ToReport};
+use erreport::Report;
+
+/// Bytes below 249 are enciphered as `c^3 mod 249`, the others are kept
+const ENCIPHER: [u8; 256] = {
+    let mut table = [0u8; 256];
+    let mut c = 0;
+    while c < 256 {
+        table[c] = if c < 249 { (c * c * c % 249) as u8 } else { c as u8 };
+        c += 1;
+    }
+    table
+};
+
+const DECIPHER: [u8; 256] = {
+    let mut table = [0u8; 256];
+    let mut c = 0;
+    while c < 256 {
+        table[ENCIPHER[c] as usize] = c as u8;
+        c += 1;
+    }
+    table
+};
+
+/// 0x2010, 0x9050 and 0x9400 to 0x9416 are stored enciphered
+pub fn is_enciphered(tag: u16) -> bool {
+    matches!(tag, 0x2010 | 0x9050 | 0x9400..=0x9416)
+}
+
+/// Deciphers the value of an enciphered tag in place.
+///
+/// Makernotes parsed by this crate are already deciphered, this is for values read
+/// by other means.
+pub fn decipher(data: &mut [u8]) {
+    for x in data.iter_mut() {
+        *x = DECIPHER[*x as usize];
+    }
+}
+
+pub fn encipher(data: &mut [u8]) {
+    for x in data.iter_mut() {
+        *x = ENCIPHER[*x as usize];
+    }
+}
+
+fn u16_at(data: &[u8], i: usize, is_le: bool) -> Option<u16> {
+    let x = data.get(i..i + 2)?.try_into().ok()?;
+    Some(if is_le {
+        u16::from_le_bytes(x)
+    } else {
+        u16::from_be_bytes(x)
+    })
+}
+
+fn u32_at(data: &[u8], i: usize, is_le: bool) -> Option<u32> {
+    let x = data.get(i..i + 4)?.try_into().ok()?;
+    Some(if is_le {
+        u32::from_le_bytes(x)
+    } else {
+        u32::from_be_bytes(x)
+    })
+}
+
+/// The layouts of Tag9050, which are told apart by the model like ExifTool does
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tag9050Layout {
+    /// SLT, NEX and the early ILCE and ILCA models
+    A,
+    /// From the ILCE-7RM2 and ILCE-6300 on
+    B,
+    /// From the ILCE-1 and ILCE-7SM3 on
+    C,
+}
+
+impl Tag9050Layout {
+    /// `None` for the DSC compacts, which have no known layout, and for models newer
+    /// than the lists
+    pub fn from_model(model: &str) -> Option<Self> {
+        const C_MODELS: &[&str] = &[
+            "ILCE-1", "ILCE-1M2", "ILCE-6700", "ILCE-7CM2", "ILCE-7CR", "ILCE-7M4", "ILCE-7RM5",
+            "ILCE-7SM3", "ILCE-9M3", "ILME-FX3", "ILME-FX30", "ZV-E1", "ZV-E10M2",
+        ];
+        const B_MODELS: &[&str] = &[
+            "ILCA-99M2", "ILCE-6100", "ILCE-6300", "ILCE-6400", "ILCE-6500", "ILCE-6600",
+            "ILCE-7C", "ILCE-7M3", "ILCE-7RM2", "ILCE-7RM3", "ILCE-7RM3A", "ILCE-7RM4",
+            "ILCE-7RM4A", "ILCE-7SM2", "ILCE-9", "ILCE-9M2", "ZV-E10",
+        ];
+        const A_MODELS: &[&str] = &[
+            "ILCA-68", "ILCA-77M2", "ILCE-3000", "ILCE-3500", "ILCE-5000", "ILCE-5100",
+            "ILCE-6000", "ILCE-7", "ILCE-7M2", "ILCE-7R", "ILCE-7S", "ILCE-QX1",
+        ];
+        let model = model.trim_matches(|x: char| x == '\0' || x.is_whitespace());
+        if C_MODELS.contains(&model) {
+            Some(Self::C)
+        } else if B_MODELS.contains(&model) {
+            Some(Self::B)
+        } else if A_MODELS.contains(&model) || model.starts_with("SLT-") || model.starts_with("NEX-")
+        {
+            Some(Self::A)
+        } else {
+            None
+        }
+    }
+}
+
+/// Tag9050, following ExifTool's Tag9050a, Tag9050b and Tag9050c
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tag9050 {
+    pub layout: Tag9050Layout,
+    /// Mechanical shutter actuations
+    pub shutter_count: Option<u32>,
+    /// Includes the electronic shutter, only in the B and C layouts
+    pub shutter_count2: Option<u32>,
+    pub flash_status: Option<u8>,
+    pub release_mode: Option<u8>,
+    /// Only in the A and B layouts
+    pub lens_mount: Option<u8>,
+    pub lens_type: Option<u16>,
+    /// The id of E-mount lenses
+    pub lens_type2: Option<u16>,
+}
+
+impl Tag9050 {
+    /// Decodes a deciphered value, `is_le` is the byte order of the makernote
+    pub fn decode(data: &[u8], layout: Tag9050Layout, is_le: bool) -> Self {
+        let byte = |i: usize| data.get(i).copied();
+        // the counts are 24 bits
+        let count = |i| u32_at(data, i, is_le).map(|x| x & 0x00ff_ffff).filter(|&x| x != 0);
+        let (shutter_count, flash_status) = match layout {
+            Tag9050Layout::A => (count(0x0032), byte(0x0031)),
+            Tag9050Layout::B | Tag9050Layout::C => (count(0x003a), byte(0x0039)),
+        };
+        let is_a = layout == Tag9050Layout::A;
+        let has_lens = layout != Tag9050Layout::C;
+
+        Self {
+            layout,
+            shutter_count,
+            shutter_count2: (!is_a).then(|| count(0x0050)).flatten(),
+            flash_status,
+            release_mode: (!is_a).then(|| byte(0x004b)).flatten(),
+            lens_mount: has_lens.then(|| byte(0x0105)).flatten(),
+            lens_type: has_lens.then(|| u16_at(data, 0x0109, is_le)).flatten(),
+            lens_type2: has_lens.then(|| u16_at(data, 0x0107, is_le)).flatten(),
+        }
+    }
+}
+
+/// Tag9400, following ExifTool's Tag9400a, Tag9400b and Tag9400c
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tag9400 {
+    /// The first byte, which tells the layouts apart
+    pub version: u8,
+    /// Counted from 1 within a burst or bracket
+    pub sequence_image_number: Option<u32>,
+    pub sequence_file_number: Option<u32>,
+    /// ExifTool's ReleaseMode2, like 0 for single frame and 1 for continuous
+    pub release_mode: Option<u8>,
+    pub shot_number_since_power_up: Option<u32>,
+}
+
+impl Tag9400 {
+    /// Decodes a deciphered value, `None` for unknown versions
+    pub fn decode(data: &[u8], is_le: bool) -> Option<Self> {
+        let version = *data.first()?;
+        let (image, file, release, shot) = match version {
+            0x07 | 0x09 | 0x0a => (0x08, 0x0c, 0x10, 0x1a),
+            0x0c => (0x08, 0x0c, 0x10, 0x1e),
+            0x23 | 0x24 | 0x26 | 0x28 | 0x31 | 0x32 | 0x33 => (0x12, 0x16, 0x1a, 0x22),
+            _ => return None,
+        };
+        Some(Self {
+            version,
+            sequence_image_number: u32_at(data, image, is_le).map(|x| x.wrapping_add(1)),
+            sequence_file_number: u32_at(data, file, is_le).map(|x| x.wrapping_add(1)),
+            release_mode: data.get(release).copied(),
+            shot_number_since_power_up: u32_at(data, shot, is_le),
+        })
+    }
+}
+
+/// Tag9402, following ExifTool
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tag9402 {
+    /// 0 manual, 2 AF-S, 3 AF-C, 4 AF-A and 6 DMF
+    pub focus_mode: Option<u8>,
+    /// Like 0 multi, 1 center and 2 flexible spot, the values depend on the model
+    pub af_area_mode: Option<u8>,
+}
+
+impl Tag9402 {
+    /// Decodes a deciphered value
+    pub fn decode(data: &[u8]) -> Self {
+        Self {
+            focus_mode: data.get(0x16).map(|x| x & 0x7f),
+            af_area_mode: data.get(0x17).copied(),
+        }
+    }
+}
+
+/// The decoded records of the Sony makernote.
+///
+/// Tag2010 and AFInfo (0x940e) are deciphered by the parser but not decoded, their
+/// layouts differ between most models.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SonyMakerNote {
+    /// `None` for the models without a known layout
+    pub tag9050: Option<Tag9050>,
+    pub tag9400: Option<Tag9400>,
+    pub tag9402: Option<Tag9402>,
+}
+
+impl SonyMakerNote {
+    /// Decodes the entries returned by `get`, which looks a tag up in the makernote IFD.
+    ///
+    /// The entries are expected to be deciphered, like the ones of `parse_exif`.
+    /// `model` is the Model of IFD0, which Tag9050 needs.
+    pub fn decode<'a>(get: impl Fn(u16) -> Option<&'a IFDItem>, model: Option<&str>) -> Self {
+        let layout = model.and_then(Tag9050Layout::from_model);
+        Self {
+            tag9050: get(0x9050)
+                .zip(layout)
+                .map(|(x, layout)| Tag9050::decode(x.data(), layout, x.is_le)),
+            tag9400: get(0x9400).and_then(|x| Tag9400::decode(x.data(), x.is_le)),
+            tag9402: get(0x9402).map(|x| Tag9402::decode(x.data())),
+        }
+    }
+
+    /// Decodes a makernote node of `parse_exif_tree`
+    pub fn from_node(node: &IFDNode, model: Option<&str>) -> Self {
+        Self::decode(|tag| node.get(tag), model)
+    }
+
+    /// Reads the makernote of an ARW or JPEG file.
+    ///
+    /// The reader is restored to its current position afterwards.
+    pub fn read<T: Read + Seek>(reader: &mut BufReader<T>) -> Result<Self, Report> {
+        let start = reader.stream_position().to_report()?;
+        let (_, blocks) = open_any(reader).to_report()?;
+
+        let mut result = Self::default();
+        if let Some(block) = blocks.iter().find(|x| x.kind == BlockKind::Tiff) {
+            let mut query = TagQuery::new();
+            let model = query.add_tag(&[0], 0x0110, "model");
+            let path_index = query.add_path(&[0, 0x8769, 0, 0x927c, 0]);
+            block.seek(reader).to_report()?;
+            let data = query.parse(BufReader::new(&mut *reader)).to_report()?;
+
+            let model = data.get_key(&model).and_then(|x| x.text()).map(|x| x.text);
+            result = Self::decode(|tag| data.get_key(&(path_index, tag)), model.as_deref());
+        }
+
+        reader.seek(SeekFrom::Start(start)).to_report()?;
+        Ok(result)
+    }
+}
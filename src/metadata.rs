@@ -0,0 +1,248 @@
+use std::io::{BufReader, Read, Seek, SeekFrom};
+
+use crate::{
+    cr3::{Cr3, Range},
+    open_any,
+    tags::{Flash, Orientation},
+    BlockKind, DateTime, ExifData, Format, GpsInfo, IFDItem, Rational, TagQuery, ToReport, Value,
+};
+use erreport::Report;
+
+const IFD0_TAGS: [(u16, &str); 7] = [
+    (0x00fe, "subfile_type"),
+    (0x0100, "image_width"),
+    (0x0101, "image_height"),
+    (0x010f, "make"),
+    (0x0110, "model"),
+    (0x0112, "orientation"),
+    (0xc62f, "camera_serial_number"),
+];
+
+const EXIF_TAGS: [(u16, &str); 15] = [
+    (0x829a, "exposure_time"),
+    (0x829d, "f_number"),
+    (0x8827, "iso"),
+    (0x8833, "iso_speed"),
+    (0x9003, "date_time_original"),
+    (0x9011, "offset_time_original"),
+    (0x9209, "flash"),
+    (0x920a, "focal_length"),
+    (0x9291, "sub_sec_time_original"),
+    (0xa002, "width"),
+    (0xa003, "height"),
+    (0xa405, "focal_length_35mm"),
+    (0xa431, "body_serial_number"),
+    (0xa433, "lens_make"),
+    (0xa434, "lens_model"),
+];
+
+const IFD1_TAGS: [(u16, &str); 2] = [(0x0201, "thumbnail_offset"), (0x0202, "thumbnail_length")];
+
+/// The common photo fields of any supported format
+#[derive(Debug, Clone)]
+pub struct Metadata {
+    pub format: Format,
+    pub make: Option<String>,
+    pub model: Option<String>,
+    pub lens_make: Option<String>,
+    pub lens_model: Option<String>,
+    /// BodySerialNumber, or CameraSerialNumber of DNG
+    pub serial_number: Option<String>,
+    pub orientation: Option<Orientation>,
+    /// DateTimeOriginal as stored, like `2023:05:01 12:34:56`
+    pub date_time_original: Option<String>,
+    /// Like `+09:00`
+    pub offset_time_original: Option<String>,
+    /// Fractions of seconds of DateTimeOriginal, like `123`
+    pub sub_sec_time_original: Option<String>,
+    /// DateTimeOriginal merged with the two above, the zone falls back to a guess
+    /// from the GPS time, see `DateTime::with_utc_reference`
+    pub capture_time: Option<DateTime>,
+    pub exposure_time: Option<Rational>,
+    pub f_number: Option<f64>,
+    pub iso: Option<u32>,
+    /// In mm
+    pub focal_length: Option<f64>,
+    pub focal_length_35mm: Option<u16>,
+    pub flash: Option<Flash>,
+    pub gps: Option<GpsInfo>,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    /// Where the embedded thumbnail JPEG is in the stream
+    pub thumbnail: Option<Range>,
+}
+
+macro_rules! fill {
+    ($self:ident.$field:ident, $value:expr) => {
+        if $self.$field.is_none() {
+            $self.$field = $value;
+        }
+    };
+}
+
+impl Metadata {
+    /// Collects the fields from every EXIF block found by `open_any`, the first block
+    /// holding a field wins. Blocks and IFDs that fail to parse are skipped.
+    ///
+    /// The reader is restored to its current position afterwards.
+    pub fn read<T: Read + Seek>(reader: &mut BufReader<T>) -> Result<Self, Report> {
+        let start = reader.stream_position().to_report()?;
+        let (format, blocks) = open_any(reader).to_report()?;
+        let mut metadata = Self::new(format);
+
+        // every IFD is parsed on its own, so a broken pointer or block only loses its
+        // own fields, the first error is returned when nothing could be read
+        let mut first_error = None;
+        let mut has_data = false;
+        for block in blocks {
+            let (ifd0, exif, gps, ifd1): (&[u16], &[u16], &[u16], &[u16]) = match block.kind {
+                BlockKind::Tiff => (&[0], &[0, 0x8769, 0], &[0, 0x8825, 0], &[1]),
+                BlockKind::ExifIfd => (&[], &[0], &[], &[]),
+                BlockKind::GpsIfd => (&[], &[], &[0], &[]),
+                BlockKind::MakerNote | BlockKind::RawIfd => continue,
+            };
+            for (path, tags) in [
+                (ifd0, &IFD0_TAGS[..]),
+                (exif, &EXIF_TAGS[..]),
+                (ifd1, &IFD1_TAGS[..]),
+                (gps, &[][..]),
+            ] {
+                if path.is_empty() {
+                    continue;
+                }
+                let mut query = TagQuery::new();
+                // every entry of a listed path is collected, so the GPS IFD needs no names
+                let gps_index = (path == gps).then(|| query.add_path(gps));
+                for (tag, name) in tags {
+                    query.add_tag(path, *tag, *name);
+                }
+
+                block.seek(reader).to_report()?;
+                match query.parse(BufReader::new(&mut *reader)) {
+                    Ok(data) => {
+                        has_data = true;
+                        metadata.fill(&data, gps_index);
+                    }
+                    Err(e) => {
+                        first_error.get_or_insert(e);
+                    }
+                }
+            }
+        }
+        if let (false, Some(e)) = (has_data, first_error) {
+            return Err(e);
+        }
+
+        if format == Format::Cr3 && metadata.thumbnail.is_none() {
+            reader.seek(SeekFrom::Start(start)).to_report()?;
+            let cr3 = Cr3::parse(reader).to_report()?;
+            metadata.thumbnail = cr3.thumbnail.map(|x| x.data);
+        }
+        metadata.capture_time = metadata.merge_capture_time();
+
+        reader.seek(SeekFrom::Start(start)).to_report()?;
+        Ok(metadata)
+    }
+
+    fn new(format: Format) -> Self {
+        Self {
+            format,
+            make: None,
+            model: None,
+            lens_make: None,
+            lens_model: None,
+            serial_number: None,
+            orientation: None,
+            date_time_original: None,
+            offset_time_original: None,
+            sub_sec_time_original: None,
+            capture_time: None,
+            exposure_time: None,
+            f_number: None,
+            iso: None,
+            focal_length: None,
+            focal_length_35mm: None,
+            flash: None,
+            gps: None,
+            width: None,
+            height: None,
+            thumbnail: None,
+        }
+    }
+
+    fn merge_capture_time(&self) -> Option<DateTime> {
+        let mut result = DateTime::parse(self.date_time_original.as_deref()?)?;
+        if let Some(x) = &self.sub_sec_time_original {
+            result = result.with_sub_sec(x);
+        }
+        if let Some(x) = &self.offset_time_original {
+            result = result.with_offset(x);
+        }
+        if let Some(x) = self.gps.as_ref().and_then(|x| x.time.as_ref()) {
+            result = result.with_utc_reference(x);
+        }
+        Some(result)
+    }
+
+    fn fill(&mut self, data: &ExifData, gps_index: Option<u16>) {
+        let get_str = |name| Some(data.get(name)?.text()?.text);
+        let get_uint = |name| data.get(name).and_then(uint);
+        let get_real = |name| data.get(name).and_then(|x| x.rational()?.to_f64());
+
+        fill!(self.make, get_str("make"));
+        fill!(self.model, get_str("model"));
+        fill!(self.lens_make, get_str("lens_make"));
+        fill!(self.lens_model, get_str("lens_model"));
+        fill!(self.serial_number, get_str("body_serial_number"));
+        fill!(self.serial_number, get_str("camera_serial_number"));
+        fill!(
+            self.orientation,
+            get_uint("orientation").and_then(|x| Orientation::from_u16(x as u16))
+        );
+
+        fill!(self.date_time_original, get_str("date_time_original"));
+        fill!(self.offset_time_original, get_str("offset_time_original"));
+        fill!(self.sub_sec_time_original, get_str("sub_sec_time_original"));
+
+        fill!(self.exposure_time, data.get("exposure_time").and_then(|x| x.rational()));
+        fill!(self.f_number, get_real("f_number"));
+        // 65535 means the sensitivity does not fit, then ISOSpeed has it
+        fill!(self.iso, get_uint("iso").filter(|&x| x != 0xffff));
+        fill!(self.iso, get_uint("iso_speed"));
+        fill!(self.focal_length, get_real("focal_length"));
+        fill!(self.focal_length_35mm, get_uint("focal_length_35mm").map(|x| x as u16));
+        fill!(self.flash, get_uint("flash").map(|x| Flash(x as u16)));
+
+        if let Some(index) = gps_index {
+            let gps = GpsInfo::decode(|tag| data.get_key(&(index, tag)));
+            fill!(self.gps, Some(gps).filter(|x| x != &GpsInfo::default()));
+        }
+
+        fill!(self.width, get_uint("width"));
+        fill!(self.height, get_uint("height"));
+        // IFD0 of raw files is usually a reduced resolution image
+        if get_uint("subfile_type").unwrap_or(0) == 0 {
+            fill!(self.width, get_uint("image_width"));
+            fill!(self.height, get_uint("image_height"));
+        }
+
+        if let (Some(offset), Some(length)) = (get_uint("thumbnail_offset"), get_uint("thumbnail_length")) {
+            fill!(
+                self.thumbnail,
+                Some(Range {
+                    offset: data.tiff_offset() + offset as u64,
+                    length: length as u64,
+                })
+            );
+        }
+    }
+}
+
+/// SHORT or LONG
+fn uint(item: &IFDItem) -> Option<u32> {
+    match item.value() {
+        Value::Short(x) => x.first().map(|&x| x as u32),
+        Value::Long(x) => x.first().copied(),
+        _ => None,
+    }
+}
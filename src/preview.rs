@@ -0,0 +1,284 @@
+use std::io::{BufReader, Read, Seek, SeekFrom, Write};
+
+use crate::{
+    cr3::{Cr3, Range},
+    image_data::{uint, uints, ImageData},
+    jpeg, open_any, parse_exif_tree,
+    raf::Raf,
+    BlockKind, Format, IFDNode, TagQuery, ToReport,
+};
+use erreport::Report;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PreviewKind {
+    Jpeg,
+    /// Uncompressed 8 bits RGB, like the IFD0 thumbnail of DNG
+    Rgb8,
+}
+
+/// Where a preview is referenced from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PreviewSource {
+    /// An IFD of the main TIFF structure
+    Ifd(Vec<u16>),
+    /// The n-th image of the JPEG's Multi-Picture Format index
+    Mpf(usize),
+    /// The JPEG in the RAF header
+    Raf,
+    /// CR3's THMB box
+    Cr3Thumbnail,
+    /// CR3's PRVW box
+    Cr3Preview,
+    /// The full size JPEG track of CR3
+    Cr3Track,
+}
+
+/// An embedded image
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Preview {
+    pub kind: PreviewKind,
+    pub width: u32,
+    pub height: u32,
+    /// Where the image is in the stream
+    pub data: Range,
+    pub source: PreviewSource,
+}
+
+impl Preview {
+    pub fn read<T: Read + Seek>(&self, reader: &mut BufReader<T>) -> Result<Vec<u8>, Report> {
+        reader.seek(SeekFrom::Start(self.data.offset)).to_report()?;
+        let mut result = vec![0u8; self.data.length as usize];
+        reader.read_exact(&mut result).to_report()?;
+        Ok(result)
+    }
+
+    fn pixels(&self) -> u64 {
+        self.width as u64 * self.height as u64
+    }
+}
+
+/// Lists the embedded images of any supported format, largest first.
+///
+/// JPEGs are only listed when their frame header can be read, so lossless JPEG raw
+/// data is left out. The makernote previews are not included, since their offsets
+/// depend on the maker. The reader is restored to its current position afterwards.
+pub fn previews<T: Read + Seek>(reader: &mut BufReader<T>) -> Result<Vec<Preview>, Report> {
+    let start = reader.stream_position().to_report()?;
+    let stream_len = reader.seek(SeekFrom::End(0)).to_report()?;
+    reader.seek(SeekFrom::Start(start)).to_report()?;
+    let (format, blocks) = open_any(reader).to_report()?;
+
+    let mut candidates = vec![];
+    match format {
+        Format::Raf => {
+            reader.seek(SeekFrom::Start(start)).to_report()?;
+            let raf = Raf::parse(reader).to_report()?;
+            let data = Range {
+                offset: start + raf.jpeg_offset as u64,
+                length: raf.jpeg_length as u64,
+            };
+            candidates.push((PreviewKind::Jpeg, data, PreviewSource::Raf, (0, 0)));
+        }
+        Format::Cr3 => {
+            reader.seek(SeekFrom::Start(start)).to_report()?;
+            let cr3 = Cr3::parse(reader).to_report()?;
+            // THMB and PRVW hold no SOF position, so their sizes are taken from the boxes
+            for (image, source) in [
+                (cr3.thumbnail, PreviewSource::Cr3Thumbnail),
+                (cr3.preview, PreviewSource::Cr3Preview),
+            ] {
+                if let Some(x) = image {
+                    let size = (x.width as u32, x.height as u32);
+                    candidates.push((PreviewKind::Jpeg, x.data, source, size));
+                }
+            }
+            let track = cr3
+                .tracks
+                .iter()
+                .find(|x| &x.codec == b"CRAW" && x.craw.is_none());
+            if let Some(data) = track.and_then(|x| x.samples.first()) {
+                candidates.push((PreviewKind::Jpeg, *data, PreviewSource::Cr3Track, (0, 0)));
+            }
+        }
+        _ => {}
+    }
+
+    for block in blocks.iter().filter(|x| x.kind == BlockKind::Tiff) {
+        // RAF blocks start from the JPEG, JPEG blocks from the TIFF header of the Exif
+        // segment, so the segments of a JPEG are located from the file start
+        let container = match format {
+            Format::Jpeg => start,
+            _ => block.offset,
+        };
+        reader.seek(SeekFrom::Start(container)).to_report()?;
+        let segments = jpeg::locate_segments(reader).ok();
+        let tiff_offset = match segments.as_ref() {
+            Some(x) => x.exif.map(|x| x.offset).unwrap_or(block.offset),
+            None => block.offset,
+        };
+        if let Some(mpf) = segments.and_then(|x| x.mpf) {
+            candidates.extend(mpf_images(reader, mpf.offset));
+        }
+
+        block.seek(reader).to_report()?;
+        // broken IFDs only lose their previews
+        if let Ok(nodes) = parse_exif_tree(BufReader::new(&mut *reader)) {
+            for node in nodes.iter() {
+                ifd_images(node, format, tiff_offset, stream_len, &mut candidates);
+            }
+        }
+    }
+
+    let mut result: Vec<Preview> = vec![];
+    for (kind, data, source, (width, height)) in candidates {
+        let end = data.offset.checked_add(data.length);
+        if data.length == 0 || end.is_none_or(|x| x > stream_len) {
+            continue;
+        }
+        if result.iter().any(|x| x.data.offset == data.offset) {
+            continue;
+        }
+        let (width, height) = match kind {
+            PreviewKind::Jpeg => {
+                reader.seek(SeekFrom::Start(data.offset)).to_report()?;
+                match jpeg::locate_segments(reader).ok().and_then(|x| x.frame) {
+                    Some(x) if x.marker != 0xffc3 => (x.width as u32, x.height as u32),
+                    Some(_) => continue,
+                    // CR3 boxes may hold no readable frame header before the scan
+                    None if width > 0 => (width, height),
+                    None => continue,
+                }
+            }
+            PreviewKind::Rgb8 => (width, height),
+        };
+        result.push(Preview {
+            kind,
+            width,
+            height,
+            data,
+            source,
+        });
+    }
+    result.sort_by_key(|x| std::cmp::Reverse((x.pixels(), x.data.length)));
+
+    reader.seek(SeekFrom::Start(start)).to_report()?;
+    Ok(result)
+}
+
+/// Copies the largest embedded image to `writer`, returns `None` when there is none
+pub fn copy_largest_preview<T: Read + Seek, W: Write>(
+    reader: &mut BufReader<T>,
+    writer: &mut W,
+) -> Result<Option<Preview>, Report> {
+    let Some(preview) = previews(reader).to_report()?.into_iter().next() else {
+        return Ok(None);
+    };
+    let start = reader.stream_position().to_report()?;
+    reader.seek(SeekFrom::Start(preview.data.offset)).to_report()?;
+    std::io::copy(&mut (&mut *reader).take(preview.data.length), writer).to_report()?;
+    reader.seek(SeekFrom::Start(start)).to_report()?;
+    Ok(Some(preview))
+}
+
+type Candidate = (PreviewKind, Range, PreviewSource, (u32, u32));
+
+fn ifd_images(
+    node: &IFDNode,
+    format: Format,
+    tiff_offset: u64,
+    stream_len: u64,
+    candidates: &mut Vec<Candidate>,
+) {
+    // makernote offsets are not relative to the TIFF header
+    if node.path.contains(&0x927c) {
+        return;
+    }
+    for child in node.children.iter() {
+        ifd_images(child, format, tiff_offset, stream_len, candidates);
+    }
+    let get_uint = |tag| node.get(tag).and_then(uint);
+    let source = || PreviewSource::Ifd(node.path.clone());
+    let jpeg = |offset: u32, length| Range {
+        offset: tiff_offset + offset as u64,
+        length,
+    };
+
+    // JPEGInterchangeFormat, used by IFD1 and the preview IFDs of ARW, NEF and PEF
+    if let (Some(offset), Some(length)) = (node.get(0x0201), node.get(0x0202)) {
+        let data = jpeg(offset.u32(), length.u32() as u64);
+        candidates.push((PreviewKind::Jpeg, data, source(), (0, 0)));
+    }
+    // JpgFromRaw of RW2
+    if format == Format::Rw2 && node.path == [0] {
+        if let Some(x) = node.get(0x002e) {
+            candidates.push((PreviewKind::Jpeg, jpeg(x.u32(), x.size64()), source(), (0, 0)));
+        }
+    }
+
+    let compression = get_uint(0x0103);
+    let Ok(image) = ImageData::from_node(node, tiff_offset, stream_len) else {
+        return;
+    };
+    let [segment] = image.segments() else {
+        return;
+    };
+    let data = Range {
+        offset: segment.offset,
+        length: segment.length,
+    };
+    let is_reduced = get_uint(0x00fe).is_some_and(|x| x & 1 == 1) || node.path[0] > 0;
+    let is_rgb8 = get_uint(0x0106) == Some(2)
+        && get_uint(0x0115) == Some(3)
+        && node.get(0x0102).and_then(uints).as_deref() == Some(&[8, 8, 8]);
+
+    match compression {
+        // the lossless raw data is filtered by its frame header later
+        Some(6 | 7) => candidates.push((PreviewKind::Jpeg, data, source(), (0, 0))),
+        Some(1) | None if is_reduced && is_rgb8 => {
+            let size = (image.width, image.height);
+            candidates.push((PreviewKind::Rgb8, data, source(), size));
+        }
+        _ => {}
+    }
+}
+
+/// The images after the primary one in the MP Index IFD of a JPEG
+fn mpf_images<T: Read + Seek>(reader: &mut BufReader<T>, mpf_offset: u64) -> Vec<Candidate> {
+    let mut query = TagQuery::new();
+    let key = query.add_tag(&[0], 0xb002, "mp_entry");
+    let parsed = reader
+        .seek(SeekFrom::Start(mpf_offset))
+        .ok()
+        .and_then(|_| query.parse(BufReader::new(&mut *reader)).ok());
+    let Some(data) = parsed else {
+        return vec![];
+    };
+    let Some(entries) = data.get_key(&key) else {
+        return vec![];
+    };
+
+    let u32 = |x: &[u8]| {
+        let x = [x[0], x[1], x[2], x[3]];
+        if data.is_le() {
+            u32::from_le_bytes(x)
+        } else {
+            u32::from_be_bytes(x)
+        }
+    };
+    entries
+        .raw()
+        .chunks_exact(16)
+        .enumerate()
+        .filter_map(|(i, x)| {
+            let (length, offset) = (u32(&x[4..8]), u32(&x[8..12]));
+            // the primary image has the offset 0
+            (offset != 0).then(|| {
+                let data = Range {
+                    offset: mpf_offset + offset as u64,
+                    length: length as u64,
+                };
+                (PreviewKind::Jpeg, data, PreviewSource::Mpf(i), (0, 0))
+            })
+        })
+        .collect()
+}
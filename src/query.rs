@@ -0,0 +1,200 @@
+use std::{
+    collections::HashMap,
+    io::{BufReader, Read, Seek},
+};
+
+use crate::{makernote, Collector, Error, IFDItem, Limits, TiffParser, ToReport};
+use erreport::Report;
+
+/// Parses a tag path like `"0/0x8769/0/0x927c/0"`.
+///
+/// Each segment is either decimal or `0x` prefixed hex. A path always alternates
+/// between IFD ids and pointer tags, so it starts and ends with an IFD id.
+pub fn parse_tag_path(path: &str) -> Result<Vec<u16>, Report> {
+    let invalid = || Error::InvalidTagPath(path.to_owned());
+
+    let result = path
+        .split('/')
+        .map(|x| {
+            let x = x.trim();
+            match x.strip_prefix("0x").or_else(|| x.strip_prefix("0X")) {
+                Some(hex) => u16::from_str_radix(hex, 16),
+                None => x.parse(),
+            }
+            .map_err(|_| invalid())
+        })
+        .collect::<Result<Vec<u16>, Error>>()
+        .to_report()?;
+
+    if result.len() % 2 == 0 {
+        return Err(invalid()).to_report();
+    }
+    Ok(result)
+}
+
+/// Formats a path back into the `"0/0x8769/0"` form accepted by `parse_tag_path`
+pub fn format_tag_path(path: &[u16]) -> String {
+    path.iter()
+        .enumerate()
+        .map(|(i, x)| {
+            if i % 2 == 0 {
+                x.to_string()
+            } else {
+                format!("{:#06x}", x)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// A list of IFD paths and named tags built at runtime.
+///
+/// This is what `gen_tags_info!` expands to, so paths added here are resolved by the
+/// same `(path_index, tag)` keys the macro constants use.
+#[derive(Debug, Clone, Default)]
+pub struct TagQuery {
+    paths: Vec<Vec<u16>>,
+    names: HashMap<String, (u16, u16)>,
+    limits: Limits,
+    makernote_make: Option<String>,
+}
+
+impl TagQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keeps the order of `paths`, so the n-th path gets the path index n
+    pub fn from_paths(paths: &[&[u16]]) -> Self {
+        Self {
+            paths: paths.iter().map(|x| x.to_vec()).collect(),
+            names: HashMap::new(),
+            limits: Limits::default(),
+            makernote_make: None,
+        }
+    }
+
+    /// Returns the path index, the path is appended if it is not in the query yet
+    pub fn add_path(&mut self, path: &[u16]) -> u16 {
+        match self.paths.iter().position(|x| x == path) {
+            Some(i) => i as u16,
+            None => {
+                self.paths.push(path.to_vec());
+                (self.paths.len() - 1) as u16
+            }
+        }
+    }
+
+    /// Returns the `(path_index, tag)` key of the added tag
+    pub fn add_tag(&mut self, path: &[u16], tag: u16, name: impl Into<String>) -> (u16, u16) {
+        let key = (self.add_path(path), tag);
+        self.names.insert(name.into(), key);
+        key
+    }
+
+    /// Same as `add_tag` with a path in the `"0/0x8769/0"` form
+    pub fn add(
+        &mut self,
+        path: &str,
+        tag: u16,
+        name: impl Into<String>,
+    ) -> Result<(u16, u16), Report> {
+        let path = parse_tag_path(path).to_report()?;
+        Ok(self.add_tag(&path, tag, name))
+    }
+
+    /// Names an existing `(path_index, tag)` key
+    pub fn name(&mut self, name: impl Into<String>, key: (u16, u16)) {
+        self.names.insert(name.into(), key);
+    }
+
+    /// Replaces the default traversal limits
+    pub fn set_limits(&mut self, limits: Limits) {
+        self.limits = limits;
+    }
+
+    /// Parses IFD0 as the makernote of `make`, for standalone makernote blocks like
+    /// CR3's CMT3, so the parser of that vendor deciphers and decrypts its entries
+    pub fn set_makernote_make(&mut self, make: impl Into<String>) {
+        self.makernote_make = Some(make.into());
+    }
+
+    pub fn paths(&self) -> &[Vec<u16>] {
+        &self.paths
+    }
+
+    pub(crate) fn path_map(&self) -> HashMap<Vec<u16>, u16> {
+        let mut path_map: HashMap<Vec<u16>, u16> = self
+            .paths
+            .iter()
+            .enumerate()
+            .map(|(i, x)| (x.clone(), i as u16))
+            .collect();
+
+        // the parser only digs into a sub IFD when its parent IFD is visited,
+        // so the missing parents are appended after the listed paths. They are numbered
+        // from the path count, since duplicated paths leave the map shorter
+        let mut next_index = self.paths.len() as u16;
+        for path in self.paths.iter() {
+            for len in (3..path.len()).step_by(2) {
+                path_map.entry(path[..len].to_vec()).or_insert_with(|| {
+                    next_index += 1;
+                    next_index - 1
+                });
+            }
+        }
+        path_map
+    }
+
+    pub fn parse<T: Read + Seek>(&self, reader: BufReader<T>) -> Result<ExifData, Report> {
+        let mut parser = TiffParser::new(reader, self.path_map()).to_report()?;
+        parser.limits = self.limits;
+        if let Some(make) = self.makernote_make.as_deref() {
+            parser.make = Some(make.to_owned());
+            parser.makernote = makernote::find(Some(make), &[]);
+        }
+        let tiff_offset = parser.addr_offset as u64;
+        let collector = parser.parse().to_report()?;
+
+        Ok(ExifData {
+            collector,
+            names: self.names.clone(),
+            is_le: parser.is_le,
+            tiff_offset,
+        })
+    }
+}
+
+/// The result of `TagQuery::parse`
+#[derive(Debug)]
+pub struct ExifData {
+    collector: Collector,
+    names: HashMap<String, (u16, u16)>,
+    is_le: bool,
+    tiff_offset: u64,
+}
+
+impl ExifData {
+    pub fn get(&self, name: &str) -> Option<&IFDItem> {
+        self.names
+            .get(name)
+            .and_then(|key| self.collector.get(key))
+    }
+    pub fn get_key(&self, key: &(u16, u16)) -> Option<&IFDItem> {
+        self.collector.get(key)
+    }
+    pub fn is_le(&self) -> bool {
+        self.is_le
+    }
+    /// Stream position of the TIFF header, which the offsets in IFD0 and its sub IFDs
+    /// are relative to
+    pub fn tiff_offset(&self) -> u64 {
+        self.tiff_offset
+    }
+    pub fn collector(&self) -> &Collector {
+        &self.collector
+    }
+    pub fn into_collector(self) -> Collector {
+        self.collector
+    }
+}
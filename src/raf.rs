@@ -0,0 +1,174 @@
+use std::io::{BufReader, Read, Seek, SeekFrom};
+
+use crate::ToReport;
+use erreport::Report;
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("Invalid RAF magic: {0:?}")]
+    InvalidMagic(String),
+    #[error("Record({0}) runs past the CFA header of {1} bytes")]
+    RecordOutOfBounds(u32, u32),
+}
+
+pub const MAGIC: &[u8; 16] = b"FUJIFILMCCD-RAW ";
+
+/// A record of the CFA header, all values are big endian
+#[derive(Debug, Clone)]
+pub struct Record {
+    pub tag: u16,
+    pub data: Box<[u8]>,
+}
+
+impl Record {
+    pub fn u16s(&self) -> Box<[u16]> {
+        self.data
+            .chunks_exact(2)
+            .map(|x| u16::from_be_bytes([x[0], x[1]]))
+            .collect()
+    }
+}
+
+/// The RAF header and the records of its CFA header.
+///
+/// Offsets are relative to the start of the RAF file.
+#[derive(Debug, Clone, Default)]
+pub struct Raf {
+    pub format_version: String,
+    pub camera_id: String,
+    pub camera: String,
+    pub dir_version: String,
+    pub jpeg_offset: u32,
+    pub jpeg_length: u32,
+    pub cfa_header_offset: u32,
+    pub cfa_header_length: u32,
+    pub cfa_offset: u32,
+    pub cfa_length: u32,
+    pub records: Vec<Record>,
+}
+
+impl Raf {
+    /// Reads the header from the current position, which should be the RAF magic
+    pub fn parse<T: Read + Seek>(reader: &mut BufReader<T>) -> Result<Self, Report> {
+        let start = reader.stream_position().to_report()?;
+
+        let mut header = [0u8; 0x6c];
+        reader.read_exact(&mut header).to_report()?;
+        if &header[..16] != MAGIC {
+            return Err(Error::InvalidMagic(
+                String::from_utf8_lossy(&header[..16]).into_owned(),
+            ))
+            .to_report();
+        }
+
+        let text = |range: std::ops::Range<usize>| {
+            String::from_utf8_lossy(&header[range])
+                .trim_end_matches('\0')
+                .to_owned()
+        };
+        let u32_at =
+            |i: usize| u32::from_be_bytes([header[i], header[i + 1], header[i + 2], header[i + 3]]);
+
+        let mut raf = Raf {
+            format_version: text(0x10..0x14),
+            camera_id: text(0x14..0x1c),
+            camera: text(0x1c..0x3c),
+            dir_version: text(0x3c..0x40),
+            jpeg_offset: u32_at(0x54),
+            jpeg_length: u32_at(0x58),
+            cfa_header_offset: u32_at(0x5c),
+            cfa_header_length: u32_at(0x60),
+            cfa_offset: u32_at(0x64),
+            cfa_length: u32_at(0x68),
+            records: vec![],
+        };
+
+        if raf.cfa_header_offset != 0 {
+            reader
+                .seek(SeekFrom::Start(start + raf.cfa_header_offset as u64))
+                .to_report()?;
+            raf.records = read_records(reader, raf.cfa_header_length).to_report()?;
+        }
+        reader.seek(SeekFrom::Start(start)).to_report()?;
+
+        Ok(raf)
+    }
+
+    pub fn record(&self, tag: u16) -> Option<&Record> {
+        self.records.iter().find(|x| x.tag == tag)
+    }
+
+    fn u16_pair(&self, tag: u16) -> Option<(u16, u16)> {
+        self.record(tag)
+            .map(|x| x.u16s())
+            .and_then(|x| Some((*x.first()?, *x.get(1)?)))
+    }
+
+    /// (height, width) of the whole sensor data
+    pub fn raw_full_size(&self) -> Option<(u16, u16)> {
+        self.u16_pair(0x0100)
+    }
+    /// (top, left) of the crop area
+    pub fn crop_top_left(&self) -> Option<(u16, u16)> {
+        self.u16_pair(0x0110)
+    }
+    /// (height, width) of the crop area
+    pub fn cropped_size(&self) -> Option<(u16, u16)> {
+        self.u16_pair(0x0111)
+    }
+    /// (height, width) of the output image
+    pub fn raw_size(&self) -> Option<(u16, u16)> {
+        self.u16_pair(0x0121)
+    }
+    pub fn fuji_layout(&self) -> Option<&[u8]> {
+        self.record(0x0130).map(|x| x.data.as_ref())
+    }
+    /// The 6x6 X-Trans color pattern, 0 => red, 1 => green, 2 => blue
+    pub fn xtrans_layout(&self) -> Option<[[u8; 6]; 6]> {
+        let data = &self.record(0x0131)?.data;
+        if data.len() < 36 {
+            return None;
+        }
+        let mut layout = [[0u8; 6]; 6];
+        for (i, x) in layout.iter_mut().flatten().enumerate() {
+            *x = data[35 - i] & 3; // stored in reversed order
+        }
+        Some(layout)
+    }
+    /// White balance levels in the G, R, G, B order
+    pub fn wb_grgb(&self) -> Option<[u16; 4]> {
+        let x = self.record(0x2ff0)?.u16s();
+        Some([*x.first()?, *x.get(1)?, *x.get(2)?, *x.get(3)?])
+    }
+}
+
+/// (count: u32, [tag: u16, size: u16, data]) in big endian, within `length` bytes
+fn read_records<T: Read + Seek>(
+    reader: &mut BufReader<T>,
+    length: u32,
+) -> Result<Vec<Record>, Report> {
+    let mut x = [0u8; 4];
+    reader.read_exact(&mut x).to_report()?;
+    let count = u32::from_be_bytes(x);
+
+    let mut remaining = length.saturating_sub(4);
+    let mut records = vec![];
+    for i in 0..count {
+        let mut x = [0u8; 4];
+        reader.read_exact(&mut x).to_report()?;
+        let tag = u16::from_be_bytes([x[0], x[1]]);
+        let size = u16::from_be_bytes([x[2], x[3]]);
+        remaining = remaining
+            .checked_sub(4 + size as u32)
+            .ok_or(Error::RecordOutOfBounds(i, length))
+            .to_report()?;
+
+        let mut data = vec![0u8; size as usize];
+        reader.read_exact(&mut data).to_report()?;
+        records.push(Record {
+            tag,
+            data: data.into(),
+        });
+    }
+    Ok(records)
+}
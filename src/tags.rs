@@ -0,0 +1,181 @@
+use crate::DataType;
+
+/// A static tag table: `tag name [types] count "description";`, the count is `_` when
+/// it is not fixed. Tags must be sorted, which is checked at compile time.
+macro_rules! gen_tag_table {
+    ($table:ident; $($tag:literal $name:ident [$($t:ident)*] $count:tt $desc:literal;)*) => {
+        pub(super) const $table: &[TagInfo] = &[$(TagInfo {
+            tag: $tag,
+            name: stringify!($name),
+            types: &[$(DataType::$t),*],
+            count: gen_tag_table!(@count $count),
+            description: $desc,
+        },)*];
+        const _: () = assert!(super::is_sorted($table), "tags are not sorted");
+    };
+    (@count _) => { None };
+    (@count $count:literal) => { Some($count) };
+}
+
+mod gps;
+mod image;
+mod interpret;
+mod makernote;
+mod photo;
+
+pub use interpret::{
+    ColorSpace, Compression, ExposureProgram, Flash, FlashMode, FlashReturn, LightSource,
+    MeteringMode, Orientation, Photometric,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TagInfo {
+    pub tag: u16,
+    /// The Exiv2 style name, like `FNumber`
+    pub name: &'static str,
+    /// The types allowed by the specification
+    pub types: &'static [DataType],
+    /// `None` when the count is not fixed
+    pub count: Option<u32>,
+    pub description: &'static str,
+}
+
+/// Makers with a known makernote layout
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Vendor {
+    Canon,
+    Nikon,
+    Sony,
+    Fujifilm,
+    Olympus,
+    Panasonic,
+    Pentax,
+    Samsung,
+}
+
+impl Vendor {
+    /// From the Make tag of IFD0
+    pub fn from_make(make: &str) -> Option<Self> {
+        let make = make.trim().to_ascii_uppercase();
+        let vendor = if make.starts_with("CANON") {
+            Self::Canon
+        } else if make.starts_with("NIKON") {
+            Self::Nikon
+        } else if make.starts_with("SONY") {
+            Self::Sony
+        } else if make.starts_with("FUJIFILM") {
+            Self::Fujifilm
+        } else if make.starts_with("OLYMPUS") || make.starts_with("OM DIGITAL") {
+            Self::Olympus
+        } else if make.starts_with("PANASONIC") {
+            Self::Panasonic
+        } else if make.starts_with("PENTAX") || make.starts_with("RICOH") {
+            Self::Pentax
+        } else if make.starts_with("SAMSUNG") {
+            Self::Samsung
+        } else {
+            return None;
+        };
+        Some(vendor)
+    }
+}
+
+/// Which table the tags of an IFD belong to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IfdContext {
+    /// IFD0, IFD1, ... and the SubIFDs, including the DNG tags
+    Image,
+    /// ExifIFD
+    Photo,
+    Gps,
+    /// Interoperability IFD
+    Iop,
+    MakerNote(Vendor),
+}
+
+impl IfdContext {
+    /// Derives the context from the last pointer tag of a path like `0/0x8769/0`.
+    ///
+    /// Makernote IFDs need the vendor, and sub IFDs of makernotes have no context.
+    pub fn from_path(path: &[u16], vendor: Option<Vendor>) -> Option<Self> {
+        match path {
+            [_] => Some(Self::Image),
+            [.., pointer, _] => match pointer {
+                0x014a => Some(Self::Image),
+                0x8769 => Some(Self::Photo),
+                0x8825 => Some(Self::Gps),
+                0xa005 => Some(Self::Iop),
+                0x927c => vendor.map(Self::MakerNote),
+                _ => None,
+            },
+            [] => None,
+        }
+    }
+
+    /// The Exiv2 group name
+    pub fn group(&self) -> &'static str {
+        match self {
+            Self::Image => "Image",
+            Self::Photo => "Photo",
+            Self::Gps => "GPSInfo",
+            Self::Iop => "Iop",
+            Self::MakerNote(Vendor::Canon) => "Canon",
+            Self::MakerNote(Vendor::Nikon) => "Nikon3",
+            Self::MakerNote(Vendor::Sony) => "Sony1",
+            Self::MakerNote(Vendor::Fujifilm) => "Fujifilm",
+            Self::MakerNote(Vendor::Olympus) => "Olympus",
+            Self::MakerNote(Vendor::Panasonic) => "Panasonic",
+            Self::MakerNote(Vendor::Pentax) => "Pentax",
+            Self::MakerNote(Vendor::Samsung) => "Samsung2",
+        }
+    }
+
+    fn table(&self) -> &'static [TagInfo] {
+        match self {
+            Self::Image => image::TAGS,
+            Self::Photo => photo::TAGS,
+            Self::Gps => gps::TAGS,
+            Self::Iop => photo::IOP_TAGS,
+            Self::MakerNote(Vendor::Canon) => makernote::CANON,
+            Self::MakerNote(Vendor::Nikon) => makernote::NIKON,
+            Self::MakerNote(Vendor::Sony) => makernote::SONY,
+            Self::MakerNote(Vendor::Fujifilm) => makernote::FUJIFILM,
+            Self::MakerNote(Vendor::Olympus) => makernote::OLYMPUS,
+            Self::MakerNote(Vendor::Panasonic) => makernote::PANASONIC,
+            Self::MakerNote(Vendor::Pentax) => makernote::PENTAX,
+            Self::MakerNote(Vendor::Samsung) => makernote::SAMSUNG,
+        }
+    }
+
+    pub fn tag_info(&self, tag: u16) -> Option<&'static TagInfo> {
+        let table = self.table();
+        table
+            .binary_search_by_key(&tag, |x| x.tag)
+            .ok()
+            .map(|i| &table[i])
+    }
+
+    /// The Exiv2 style key, like `Exif.Photo.FNumber`, unknown tags are shown in hex
+    pub fn key(&self, tag: u16) -> String {
+        match self.tag_info(tag) {
+            Some(info) => format!("Exif.{}.{}", self.group(), info.name),
+            None => format!("Exif.{}.{:#06x}", self.group(), tag),
+        }
+    }
+}
+
+/// Looks up a tag of the IFD at `path`, see `IfdContext::from_path`
+pub fn tag_info(path: &[u16], vendor: Option<Vendor>, tag: u16) -> Option<&'static TagInfo> {
+    IfdContext::from_path(path, vendor)?.tag_info(tag)
+}
+
+const fn is_sorted(table: &[TagInfo]) -> bool {
+    let mut i = 1;
+    while i < table.len() {
+        if table[i - 1].tag >= table[i].tag {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
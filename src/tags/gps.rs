@@ -0,0 +1,37 @@
+use super::TagInfo;
+use crate::DataType;
+
+gen_tag_table! { TAGS;
+    0x0000 GPSVersionID [Byte] 4 "Version of the GPS IFD, 2.2.0.0 or later";
+    0x0001 GPSLatitudeRef [Ascii] 2 "N for north or S for south latitude";
+    0x0002 GPSLatitude [Rational] 3 "Latitude as degrees, minutes and seconds";
+    0x0003 GPSLongitudeRef [Ascii] 2 "E for east or W for west longitude";
+    0x0004 GPSLongitude [Rational] 3 "Longitude as degrees, minutes and seconds";
+    0x0005 GPSAltitudeRef [Byte] 1 "0 when above and 1 when below the sea level";
+    0x0006 GPSAltitude [Rational] 1 "Altitude in meters";
+    0x0007 GPSTimeStamp [Rational] 3 "UTC time as hours, minutes and seconds";
+    0x0008 GPSSatellites [Ascii] _ "Satellites used for the measurement";
+    0x0009 GPSStatus [Ascii] 2 "A when the measurement is in progress, V when interrupted";
+    0x000a GPSMeasureMode [Ascii] 2 "2 or 3 dimensional measurement";
+    0x000b GPSDOP [Rational] 1 "Dilution of precision";
+    0x000c GPSSpeedRef [Ascii] 2 "Unit of the speed: K, M or N";
+    0x000d GPSSpeed [Rational] 1 "Speed of the receiver";
+    0x000e GPSTrackRef [Ascii] 2 "T for true or M for magnetic direction of movement";
+    0x000f GPSTrack [Rational] 1 "Direction of movement in degrees";
+    0x0010 GPSImgDirectionRef [Ascii] 2 "T for true or M for magnetic image direction";
+    0x0011 GPSImgDirection [Rational] 1 "Direction of the image in degrees";
+    0x0012 GPSMapDatum [Ascii] _ "Geodetic survey data, like WGS-84";
+    0x0013 GPSDestLatitudeRef [Ascii] 2 "N or S latitude of the destination";
+    0x0014 GPSDestLatitude [Rational] 3 "Latitude of the destination";
+    0x0015 GPSDestLongitudeRef [Ascii] 2 "E or W longitude of the destination";
+    0x0016 GPSDestLongitude [Rational] 3 "Longitude of the destination";
+    0x0017 GPSDestBearingRef [Ascii] 2 "T or M bearing of the destination";
+    0x0018 GPSDestBearing [Rational] 1 "Bearing to the destination in degrees";
+    0x0019 GPSDestDistanceRef [Ascii] 2 "Unit of the destination distance: K, M or N";
+    0x001a GPSDestDistance [Rational] 1 "Distance to the destination";
+    0x001b GPSProcessingMethod [Undefined] _ "Name of the location method, with a charset prefix";
+    0x001c GPSAreaInformation [Undefined] _ "Name of the GPS area, with a charset prefix";
+    0x001d GPSDateStamp [Ascii] 11 "UTC date as YYYY:MM:DD";
+    0x001e GPSDifferential [Short] 1 "Whether differential correction is applied";
+    0x001f GPSHPositioningError [Rational] 1 "Horizontal positioning error in meters";
+}
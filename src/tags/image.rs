@@ -0,0 +1,254 @@
+use super::TagInfo;
+use crate::DataType;
+
+// baseline TIFF, its extensions, TIFF/EP and DNG 1.7
+gen_tag_table! { TAGS;
+    0x000b ProcessingSoftware [Ascii] _ "Software used to process the image";
+    0x00fe NewSubfileType [Long] 1 "Kind of the image, like 1 for a reduced resolution image";
+    0x00ff SubfileType [Short] 1 "Old form of NewSubfileType";
+    0x0100 ImageWidth [Short Long] 1 "Number of columns";
+    0x0101 ImageLength [Short Long] 1 "Number of rows";
+    0x0102 BitsPerSample [Short] _ "Bits of each component";
+    0x0103 Compression [Short] 1 "Compression scheme";
+    0x0106 PhotometricInterpretation [Short] 1 "Color space of the image data";
+    0x0107 Thresholding [Short] 1 "Technique used to convert gray to black and white";
+    0x0108 CellWidth [Short] 1 "Width of the dithering or halftoning matrix";
+    0x0109 CellLength [Short] 1 "Height of the dithering or halftoning matrix";
+    0x010a FillOrder [Short] 1 "Logical order of bits within a byte";
+    0x010d DocumentName [Ascii] _ "Name of the scanned document";
+    0x010e ImageDescription [Ascii] _ "Title of the image";
+    0x010f Make [Ascii] _ "Manufacturer of the camera";
+    0x0110 Model [Ascii] _ "Model of the camera";
+    0x0111 StripOffsets [Short Long Long8] _ "Offset of each strip";
+    0x0112 Orientation [Short] 1 "Orientation of the image in rows and columns";
+    0x0115 SamplesPerPixel [Short] 1 "Number of components per pixel";
+    0x0116 RowsPerStrip [Short Long] 1 "Rows per strip";
+    0x0117 StripByteCounts [Short Long Long8] _ "Bytes of each strip";
+    0x0118 MinSampleValue [Short] _ "Minimum component value";
+    0x0119 MaxSampleValue [Short] _ "Maximum component value";
+    0x011a XResolution [Rational] 1 "Pixels per ResolutionUnit in width";
+    0x011b YResolution [Rational] 1 "Pixels per ResolutionUnit in height";
+    0x011c PlanarConfiguration [Short] 1 "Chunky or planar storage of the components";
+    0x011d PageName [Ascii] _ "Name of the page";
+    0x011e XPosition [Rational] 1 "X offset of the image in ResolutionUnit";
+    0x011f YPosition [Rational] 1 "Y offset of the image in ResolutionUnit";
+    0x0122 GrayResponseUnit [Short] 1 "Precision of GrayResponseCurve";
+    0x0123 GrayResponseCurve [Short] _ "Optical density of each gray value";
+    0x0124 T4Options [Long] 1 "T.4 encoding options";
+    0x0125 T6Options [Long] 1 "T.6 encoding options";
+    0x0128 ResolutionUnit [Short] 1 "Unit of XResolution and YResolution";
+    0x0129 PageNumber [Short] 2 "Page number and total pages";
+    0x012d TransferFunction [Short] 768 "Transfer function of the image";
+    0x0131 Software [Ascii] _ "Software used to create the image";
+    0x0132 DateTime [Ascii] 20 "When the file was changed";
+    0x013b Artist [Ascii] _ "Creator of the image";
+    0x013c HostComputer [Ascii] _ "Computer used to create the image";
+    0x013d Predictor [Short] 1 "Predictor applied before the compression";
+    0x013e WhitePoint [Rational] 2 "Chromaticity of the white point";
+    0x013f PrimaryChromaticities [Rational] 6 "Chromaticities of the primaries";
+    0x0140 ColorMap [Short] _ "Color map of palette images";
+    0x0141 HalftoneHints [Short] 2 "Range of the highlight and shadow values";
+    0x0142 TileWidth [Short Long] 1 "Columns of each tile";
+    0x0143 TileLength [Short Long] 1 "Rows of each tile";
+    0x0144 TileOffsets [Long Long8] _ "Offset of each tile";
+    0x0145 TileByteCounts [Short Long Long8] _ "Bytes of each tile";
+    0x014a SubIFDs [Long Ifd Long8 Ifd8] _ "Offsets of the child IFDs";
+    0x014c InkSet [Short] 1 "Set of inks used in a separated image";
+    0x014d InkNames [Ascii] _ "Names of the inks";
+    0x014e NumberOfInks [Short] 1 "Number of inks";
+    0x0150 DotRange [Byte Short] _ "Component values of 0% and 100% dots";
+    0x0151 TargetPrinter [Ascii] _ "Intended printing environment";
+    0x0152 ExtraSamples [Short] _ "Meaning of the extra components";
+    0x0153 SampleFormat [Short] _ "Interpretation of each component";
+    0x0156 TransferRange [Short] 6 "Range of the transfer function";
+    0x0157 ClipPath [Byte] _ "Clipping path of the image";
+    0x0158 XClipPathUnits [Long] 1 "Horizontal units of the clipping path";
+    0x0159 YClipPathUnits [Long] 1 "Vertical units of the clipping path";
+    0x015a Indexed [Short] 1 "Whether the image is indexed";
+    0x015b JPEGTables [Undefined] _ "Shared JPEG quantization and Huffman tables";
+    0x015f OPIProxy [Short] 1 "Whether a higher resolution version exists";
+    0x0200 JPEGProc [Long] 1 "Old style JPEG process";
+    0x0201 JPEGInterchangeFormat [Long] 1 "Offset of the JPEG thumbnail";
+    0x0202 JPEGInterchangeFormatLength [Long] 1 "Bytes of the JPEG thumbnail";
+    0x0203 JPEGRestartInterval [Short] 1 "Old style JPEG restart interval";
+    0x0205 JPEGLosslessPredictors [Short] _ "Old style JPEG lossless predictors";
+    0x0206 JPEGPointTransforms [Short] _ "Old style JPEG point transforms";
+    0x0207 JPEGQTables [Long] _ "Offsets of the old style JPEG quantization tables";
+    0x0208 JPEGDCTables [Long] _ "Offsets of the old style JPEG DC tables";
+    0x0209 JPEGACTables [Long] _ "Offsets of the old style JPEG AC tables";
+    0x0211 YCbCrCoefficients [Rational] 3 "Coefficients of the RGB to YCbCr conversion";
+    0x0212 YCbCrSubSampling [Short] 2 "Chroma subsampling factors";
+    0x0213 YCbCrPositioning [Short] 1 "Position of the chroma samples";
+    0x0214 ReferenceBlackWhite [Rational] 6 "Reference black and white values";
+    0x02bc XMLPacket [Byte] _ "XMP metadata";
+    0x4746 Rating [Short] 1 "Rating from 0 to 5";
+    0x4749 RatingPercent [Short] 1 "Rating in percent";
+    0x800d ImageID [Ascii] _ "OPI image ID";
+    0x828d CFARepeatPatternDim [Short] 2 "Rows and columns of the CFA pattern";
+    0x828e CFAPattern [Byte] _ "Color filter array pattern";
+    0x828f BatteryLevel [Rational] 1 "Battery level";
+    0x8298 Copyright [Ascii] _ "Copyright notice";
+    0x829a ExposureTime [Rational] 1 "Exposure time in seconds";
+    0x829d FNumber [Rational] 1 "The F number";
+    0x83bb IPTCNAA [Long Undefined] _ "IPTC metadata";
+    0x8649 ImageResources [Byte] _ "Photoshop image resources";
+    0x8769 ExifTag [Long Ifd] 1 "Offset of the Exif IFD";
+    0x8773 InterColorProfile [Undefined] _ "ICC profile";
+    0x8822 ExposureProgram [Short] 1 "Program used to set the exposure";
+    0x8824 SpectralSensitivity [Ascii] _ "Spectral sensitivity of each channel";
+    0x8825 GPSTag [Long Ifd] 1 "Offset of the GPS IFD";
+    0x8827 ISOSpeedRatings [Short] _ "ISO speed";
+    0x8828 OECF [Undefined] _ "Opto-electric conversion function";
+    0x8829 Interlace [Short] 1 "Field number of multifield images";
+    0x882a TimeZoneOffset [SShort] _ "Time zone offsets from GMT in hours";
+    0x882b SelfTimerMode [Short] 1 "Seconds of the self timer";
+    0x9003 DateTimeOriginal [Ascii] 20 "When the original image was taken";
+    0x9102 CompressedBitsPerPixel [Rational] 1 "Compression mode in bits per pixel";
+    0x9201 ShutterSpeedValue [SRational] 1 "Shutter speed in APEX";
+    0x9202 ApertureValue [Rational] 1 "Aperture in APEX";
+    0x9203 BrightnessValue [SRational] 1 "Brightness in APEX";
+    0x9204 ExposureBiasValue [SRational] 1 "Exposure bias in APEX";
+    0x9205 MaxApertureValue [Rational] 1 "Smallest F number of the lens in APEX";
+    0x9206 SubjectDistance [SRational] 1 "Distance to the subject in meters";
+    0x9207 MeteringMode [Short] 1 "Metering mode";
+    0x9208 LightSource [Short] 1 "Kind of light source";
+    0x9209 Flash [Short] 1 "Flash status bits";
+    0x920a FocalLength [Rational] 1 "Actual focal length of the lens in mm";
+    0x920b FlashEnergy [Rational] _ "Strobe energy in BCPS";
+    0x920c SpatialFrequencyResponse [Undefined] _ "Spatial frequency table of ISO 12233";
+    0x920d Noise [Undefined] _ "Noise measurement values";
+    0x920e FocalPlaneXResolution [Rational] 1 "Pixels per FocalPlaneResolutionUnit in width";
+    0x920f FocalPlaneYResolution [Rational] 1 "Pixels per FocalPlaneResolutionUnit in height";
+    0x9210 FocalPlaneResolutionUnit [Short] 1 "Unit of the focal plane resolutions";
+    0x9211 ImageNumber [Long] 1 "Number assigned to the image";
+    0x9212 SecurityClassification [Ascii] _ "Security classification";
+    0x9213 ImageHistory [Ascii] _ "Record of the changes to the image";
+    0x9214 SubjectLocation [Short] _ "Location of the main subject";
+    0x9215 ExposureIndex [Rational] _ "Selected exposure index";
+    0x9216 TIFFEPStandardID [Byte] 4 "TIFF/EP version";
+    0x9217 SensingMethod [Short] 1 "Image sensor type";
+    0x9c9b XPTitle [Byte] _ "Title in UCS-2, used by Windows";
+    0x9c9c XPComment [Byte] _ "Comment in UCS-2, used by Windows";
+    0x9c9d XPAuthor [Byte] _ "Author in UCS-2, used by Windows";
+    0x9c9e XPKeywords [Byte] _ "Keywords in UCS-2, used by Windows";
+    0x9c9f XPSubject [Byte] _ "Subject in UCS-2, used by Windows";
+    0xa480 GDALMetadata [Ascii] _ "GDAL metadata as XML";
+    0xa481 GDALNoData [Ascii] _ "GDAL no data value";
+    0xc4a5 PrintImageMatching [Undefined] _ "Epson print image matching data";
+    0xc612 DNGVersion [Byte] 4 "DNG version";
+    0xc613 DNGBackwardVersion [Byte] 4 "Oldest DNG version the file is compatible with";
+    0xc614 UniqueCameraModel [Ascii] _ "Unique non-localized name of the camera model";
+    0xc615 LocalizedCameraModel [Ascii Byte] _ "Localized name of the camera model";
+    0xc616 CFAPlaneColor [Byte] _ "Color of each CFA plane";
+    0xc617 CFALayout [Short] 1 "Spatial layout of the CFA";
+    0xc618 LinearizationTable [Short] _ "Lookup table mapping raw values to linear values";
+    0xc619 BlackLevelRepeatDim [Short] 2 "Rows and columns of the BlackLevel pattern";
+    0xc61a BlackLevel [Short Long Rational] _ "Zero light encoding level";
+    0xc61b BlackLevelDeltaH [SRational] _ "Per column black level difference";
+    0xc61c BlackLevelDeltaV [SRational] _ "Per row black level difference";
+    0xc61d WhiteLevel [Short Long] _ "Fully saturated encoding level";
+    0xc61e DefaultScale [Rational] 2 "Default scale factors for non-square pixels";
+    0xc61f DefaultCropOrigin [Short Long Rational] 2 "Origin of the final image area";
+    0xc620 DefaultCropSize [Short Long Rational] 2 "Size of the final image area";
+    0xc621 ColorMatrix1 [SRational] _ "XYZ to camera space matrix under CalibrationIlluminant1";
+    0xc622 ColorMatrix2 [SRational] _ "XYZ to camera space matrix under CalibrationIlluminant2";
+    0xc623 CameraCalibration1 [SRational] _ "Individual camera calibration under CalibrationIlluminant1";
+    0xc624 CameraCalibration2 [SRational] _ "Individual camera calibration under CalibrationIlluminant2";
+    0xc625 ReductionMatrix1 [SRational] _ "Dimensionality reduction under CalibrationIlluminant1";
+    0xc626 ReductionMatrix2 [SRational] _ "Dimensionality reduction under CalibrationIlluminant2";
+    0xc627 AnalogBalance [Rational] _ "Gain applied to the stored raw values";
+    0xc628 AsShotNeutral [Short Rational] _ "Neutral white balance in camera space";
+    0xc629 AsShotWhiteXY [Rational] 2 "Selected white balance as xy chromaticity";
+    0xc62a BaselineExposure [SRational] 1 "Exposure compensation to apply in EV";
+    0xc62b BaselineNoise [Rational] 1 "Relative noise level at ISO 100";
+    0xc62c BaselineSharpness [Rational] 1 "Relative amount of sharpening";
+    0xc62d BayerGreenSplit [Long] 1 "Tracking difference of the green channels";
+    0xc62e LinearResponseLimit [Rational] 1 "Fraction of the range with a linear response";
+    0xc62f CameraSerialNumber [Ascii] _ "Serial number of the camera";
+    0xc630 LensInfo [Rational] 4 "Minimum and maximum focal lengths and F numbers";
+    0xc631 ChromaBlurRadius [Rational] 1 "Chroma blur radius for the demosaicing";
+    0xc632 AntiAliasStrength [Rational] 1 "Strength of the anti-alias filter";
+    0xc633 ShadowScale [Rational] 1 "Scale of the shadows slider";
+    0xc634 DNGPrivateData [Byte] _ "Private data of the DNG writer";
+    0xc635 MakerNoteSafety [Short] 1 "Whether the makernote survives editing";
+    0xc65a CalibrationIlluminant1 [Short] 1 "Illuminant of the first calibration";
+    0xc65b CalibrationIlluminant2 [Short] 1 "Illuminant of the second calibration";
+    0xc65c BestQualityScale [Rational] 1 "Scale to get the best quality";
+    0xc65d RawDataUniqueID [Byte] 16 "Unique ID of the raw image data";
+    0xc68b OriginalRawFileName [Ascii Byte] _ "File name of the original raw file";
+    0xc68c OriginalRawFileData [Undefined] _ "Contents of the original raw file";
+    0xc68d ActiveArea [Short Long] 4 "Top, left, bottom and right of the active area";
+    0xc68e MaskedAreas [Short Long] _ "Rectangles of the masked pixels";
+    0xc68f AsShotICCProfile [Undefined] _ "ICC profile for the as shot rendering";
+    0xc690 AsShotPreProfileMatrix [SRational] _ "Matrix applied before AsShotICCProfile";
+    0xc691 CurrentICCProfile [Undefined] _ "ICC profile for the current rendering";
+    0xc692 CurrentPreProfileMatrix [SRational] _ "Matrix applied before CurrentICCProfile";
+    0xc6bf ColorimetricReference [Short] 1 "Colorimetric reference of the output";
+    0xc6f3 CameraCalibrationSignature [Ascii Byte] _ "Signature of the camera calibration";
+    0xc6f4 ProfileCalibrationSignature [Ascii Byte] _ "Signature of the profile calibration";
+    0xc6f5 ExtraCameraProfiles [Long Ifd] _ "Offsets of the extra camera profiles";
+    0xc6f6 AsShotProfileName [Ascii Byte] _ "Name of the profile to use by default";
+    0xc6f7 NoiseReductionApplied [Rational] 1 "Amount of noise reduction applied";
+    0xc6f8 ProfileName [Ascii Byte] _ "Name of the camera profile";
+    0xc6f9 ProfileHueSatMapDims [Long] 3 "Hue, saturation and value divisions of the maps";
+    0xc6fa ProfileHueSatMapData1 [Float] _ "Hue, saturation and value map under CalibrationIlluminant1";
+    0xc6fb ProfileHueSatMapData2 [Float] _ "Hue, saturation and value map under CalibrationIlluminant2";
+    0xc6fc ProfileToneCurve [Float] _ "Default tone curve of the profile";
+    0xc6fd ProfileEmbedPolicy [Long] 1 "Usage rules of the profile";
+    0xc6fe ProfileCopyright [Ascii Byte] _ "Copyright of the profile";
+    0xc714 ForwardMatrix1 [SRational] _ "Camera to XYZ D50 matrix under CalibrationIlluminant1";
+    0xc715 ForwardMatrix2 [SRational] _ "Camera to XYZ D50 matrix under CalibrationIlluminant2";
+    0xc716 PreviewApplicationName [Ascii Byte] _ "Application that rendered the preview";
+    0xc717 PreviewApplicationVersion [Ascii Byte] _ "Version of the application that rendered the preview";
+    0xc718 PreviewSettingsName [Ascii Byte] _ "Name of the settings used for the preview";
+    0xc719 PreviewSettingsDigest [Byte] 16 "Digest of the settings used for the preview";
+    0xc71a PreviewColorSpace [Long] 1 "Color space of the preview";
+    0xc71b PreviewDateTime [Ascii] _ "When the preview was rendered";
+    0xc71c RawImageDigest [Byte] 16 "MD5 digest of the raw image data";
+    0xc71d OriginalRawFileDigest [Byte] 16 "MD5 digest of OriginalRawFileData";
+    0xc71e SubTileBlockSize [Short Long] 2 "Rows and columns of the sub tile blocks";
+    0xc71f RowInterleaveFactor [Short Long] 1 "Rows interleaved in the image data";
+    0xc725 ProfileLookTableDims [Long] 3 "Hue, saturation and value divisions of the look table";
+    0xc726 ProfileLookTableData [Float] _ "Hue, saturation and value look table";
+    0xc740 OpcodeList1 [Undefined] _ "Opcodes applied to the raw image as read";
+    0xc741 OpcodeList2 [Undefined] _ "Opcodes applied after the linearization";
+    0xc74e OpcodeList3 [Undefined] _ "Opcodes applied after the demosaicing";
+    0xc761 NoiseProfile [Double] _ "Noise model of each color plane";
+    0xc763 TimeCodes [Byte] _ "SMPTE time codes";
+    0xc764 FrameRate [SRational] 1 "Frames per second";
+    0xc772 TStop [SRational] _ "T stop of the lens";
+    0xc789 ReelName [Ascii] _ "Name of the film reel";
+    0xc791 OriginalDefaultFinalSize [Short Long] 2 "Default final size of the original file";
+    0xc792 OriginalBestQualityFinalSize [Short Long] 2 "Best quality final size of the original file";
+    0xc793 OriginalDefaultCropSize [Short Long Rational] 2 "Default crop size of the original file";
+    0xc7a1 CameraLabel [Ascii] _ "Label of the camera in a multi camera setup";
+    0xc7a3 ProfileHueSatMapEncoding [Long] 1 "Encoding of the hue, saturation and value maps";
+    0xc7a4 ProfileLookTableEncoding [Long] 1 "Encoding of the look table";
+    0xc7a5 BaselineExposureOffset [SRational] 1 "Baseline exposure offset of the profile";
+    0xc7a6 DefaultBlackRender [Long] 1 "Default black rendering";
+    0xc7a7 NewRawImageDigest [Byte] 16 "MD5 digest of the raw image data, new algorithm";
+    0xc7a8 RawToPreviewGain [Double] 1 "Gain between the raw and the preview image";
+    0xc7b5 DefaultUserCrop [Rational] 4 "Top, left, bottom and right of the default user crop";
+    0xcd2d ProfileGainTableMap [Undefined] _ "Spatially varying gain table";
+    0xcd2e SemanticName [Ascii] _ "Semantic name of the mask";
+    0xcd30 SemanticInstanceID [Ascii] _ "Instance ID of the semantic mask";
+    0xcd31 CalibrationIlluminant3 [Short] 1 "Illuminant of the third calibration";
+    0xcd32 CameraCalibration3 [SRational] _ "Individual camera calibration under CalibrationIlluminant3";
+    0xcd33 ColorMatrix3 [SRational] _ "XYZ to camera space matrix under CalibrationIlluminant3";
+    0xcd34 ForwardMatrix3 [SRational] _ "Camera to XYZ D50 matrix under CalibrationIlluminant3";
+    0xcd35 IlluminantData1 [Undefined] _ "Spectral data of CalibrationIlluminant1";
+    0xcd36 IlluminantData2 [Undefined] _ "Spectral data of CalibrationIlluminant2";
+    0xcd37 IlluminantData3 [Undefined] _ "Spectral data of CalibrationIlluminant3";
+    0xcd38 MaskSubArea [Long] 4 "Area of the mask within the image";
+    0xcd39 ProfileHueSatMapData3 [Float] _ "Hue, saturation and value map under CalibrationIlluminant3";
+    0xcd3a ReductionMatrix3 [SRational] _ "Dimensionality reduction under CalibrationIlluminant3";
+    0xcd3f RGBTables [Undefined] _ "RGB lookup tables of the profile";
+    0xcd43 ColumnInterleaveFactor [Short Long] 1 "Columns interleaved in the image data";
+    0xcd44 ImageSequenceInfo [Undefined] _ "Position of the image in a sequence";
+    0xcd46 ImageStats [Undefined] _ "Statistics of the image data";
+    0xcd47 ProfileDynamicRange [Undefined] _ "Dynamic range of the profile";
+    0xcd48 ProfileGroupName [Ascii Byte] _ "Group of the camera profile";
+    0xcd49 JXLDistance [Float] 1 "JPEG XL distance parameter";
+    0xcd4a JXLEffort [Long] 1 "JPEG XL effort parameter";
+    0xcd4b JXLDecodeSpeed [Long] 1 "JPEG XL decode speed parameter";
+}
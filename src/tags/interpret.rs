@@ -0,0 +1,247 @@
+use std::fmt;
+
+use super::IfdContext;
+use crate::{IFDItem, Value};
+
+/// An enum of the known values of a SHORT tag: `value Variant "display";`
+macro_rules! gen_value_enum {
+    ($(#[$meta:meta])* $name:ident; $($value:literal $variant:ident $display:literal;)*) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($variant,)*
+        }
+
+        impl $name {
+            /// `None` for values not defined by the specification
+            pub fn from_u16(value: u16) -> Option<Self> {
+                match value {
+                    $($value => Some(Self::$variant),)*
+                    _ => None,
+                }
+            }
+            pub fn value(&self) -> u16 {
+                match self {
+                    $(Self::$variant => $value,)*
+                }
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(match self {
+                    $(Self::$variant => $display,)*
+                })
+            }
+        }
+    };
+}
+
+gen_value_enum! {
+    /// 0x0112, named after where the 0th row and column are
+    Orientation;
+    1 TopLeft "Horizontal (normal)";
+    2 TopRight "Mirror horizontal";
+    3 BottomRight "Rotate 180";
+    4 BottomLeft "Mirror vertical";
+    5 LeftTop "Mirror horizontal and rotate 270 CW";
+    6 RightTop "Rotate 90 CW";
+    7 RightBottom "Mirror horizontal and rotate 90 CW";
+    8 LeftBottom "Rotate 270 CW";
+}
+
+gen_value_enum! {
+    /// 0x9207
+    MeteringMode;
+    0 Unknown "Unknown";
+    1 Average "Average";
+    2 CenterWeightedAverage "Center-weighted average";
+    3 Spot "Spot";
+    4 MultiSpot "Multi-spot";
+    5 Pattern "Multi-segment";
+    6 Partial "Partial";
+    255 Other "Other";
+}
+
+gen_value_enum! {
+    /// 0x8822
+    ExposureProgram;
+    0 NotDefined "Not defined";
+    1 Manual "Manual";
+    2 Normal "Program AE";
+    3 AperturePriority "Aperture-priority AE";
+    4 ShutterPriority "Shutter speed priority AE";
+    5 Creative "Creative (slow speed)";
+    6 Action "Action (high speed)";
+    7 Portrait "Portrait";
+    8 Landscape "Landscape";
+}
+
+gen_value_enum! {
+    /// 0xa001, Adobe RGB is not in the specification but written by many cameras
+    ColorSpace;
+    1 Srgb "sRGB";
+    2 AdobeRgb "Adobe RGB";
+    0xffff Uncalibrated "Uncalibrated";
+}
+
+gen_value_enum! {
+    /// 0x0103, including the vendor specific raw compressions
+    Compression;
+    1 Uncompressed "Uncompressed";
+    2 Ccitt1D "CCITT 1D";
+    3 T4 "T4/Group 3 Fax";
+    4 T6 "T6/Group 4 Fax";
+    5 Lzw "LZW";
+    6 OldJpeg "JPEG (old-style)";
+    7 Jpeg "JPEG";
+    8 AdobeDeflate "Adobe Deflate";
+    9 JbigBw "JBIG B&W";
+    10 JbigColor "JBIG Color";
+    99 JpegPreview "JPEG";
+    32767 SonyArw "Sony ARW Compressed";
+    32769 PackedRaw "Packed RAW";
+    32770 SamsungSrw "Samsung SRW Compressed";
+    32773 PackBits "PackBits";
+    32867 KodakKdc "Kodak KDC Compressed";
+    32946 Deflate "Deflate";
+    34712 Jpeg2000 "JPEG 2000";
+    34713 NikonNef "Nikon NEF Compressed";
+    34892 LossyJpeg "Lossy JPEG";
+    52546 JpegXl "JPEG XL";
+    65000 KodakDcr "Kodak DCR Compressed";
+    65535 PentaxPef "Pentax PEF Compressed";
+}
+
+gen_value_enum! {
+    /// 0x0106
+    Photometric;
+    0 WhiteIsZero "WhiteIsZero";
+    1 BlackIsZero "BlackIsZero";
+    2 Rgb "RGB";
+    3 Palette "RGB Palette";
+    4 TransparencyMask "Transparency Mask";
+    5 Cmyk "CMYK";
+    6 YCbCr "YCbCr";
+    8 CieLab "CIELab";
+    9 IccLab "ICCLab";
+    10 ItuLab "ITULab";
+    32803 Cfa "Color Filter Array";
+    32844 LogL "Pixar LogL";
+    32845 LogLuv "Pixar LogLuv";
+    34892 LinearRaw "Linear Raw";
+    51177 Depth "Depth Map";
+    52527 PhotometricMask "Semantic Mask";
+}
+
+gen_value_enum! {
+    /// 0x9208, also used by the DNG calibration illuminants
+    LightSource;
+    0 Unknown "Unknown";
+    1 Daylight "Daylight";
+    2 Fluorescent "Fluorescent";
+    3 Tungsten "Tungsten (incandescent)";
+    4 Flash "Flash";
+    9 FineWeather "Fine weather";
+    10 CloudyWeather "Cloudy";
+    11 Shade "Shade";
+    12 DaylightFluorescent "Daylight fluorescent (D 5700 - 7100K)";
+    13 DayWhiteFluorescent "Day white fluorescent (N 4600 - 5500K)";
+    14 CoolWhiteFluorescent "Cool white fluorescent (W 3800 - 4500K)";
+    15 WhiteFluorescent "White fluorescent (WW 3250 - 3800K)";
+    16 WarmWhiteFluorescent "Warm white fluorescent (L 2600 - 3250K)";
+    17 StandardLightA "Standard light A";
+    18 StandardLightB "Standard light B";
+    19 StandardLightC "Standard light C";
+    20 D55 "D55";
+    21 D65 "D65";
+    22 D75 "D75";
+    23 D50 "D50";
+    24 IsoStudioTungsten "ISO studio tungsten";
+    255 Other "Other light source";
+}
+
+gen_value_enum! {
+    /// Bits 1 and 2 of Flash
+    FlashReturn;
+    0 NoDetection "No return detection";
+    2 NotDetected "Return not detected";
+    3 Detected "Return detected";
+}
+
+gen_value_enum! {
+    /// Bits 3 and 4 of Flash
+    FlashMode;
+    0 Unknown "Unknown";
+    1 CompulsoryFiring "Compulsory firing";
+    2 CompulsorySuppression "Compulsory suppression";
+    3 Auto "Auto";
+}
+
+/// 0x9209, a bitfield of the flash status
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Flash(pub u16);
+
+impl Flash {
+    pub fn fired(&self) -> bool {
+        self.0 & 0x01 != 0
+    }
+    /// `None` for the reserved value 1
+    pub fn return_light(&self) -> Option<FlashReturn> {
+        FlashReturn::from_u16(self.0 >> 1 & 0b11)
+    }
+    pub fn mode(&self) -> FlashMode {
+        FlashMode::from_u16(self.0 >> 3 & 0b11).unwrap()
+    }
+    /// False when the camera has no flash
+    pub fn has_function(&self) -> bool {
+        self.0 & 0x20 == 0
+    }
+    pub fn red_eye_reduction(&self) -> bool {
+        self.0 & 0x40 != 0
+    }
+}
+
+impl fmt::Display for Flash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.has_function() {
+            return f.write_str("No flash function");
+        }
+        f.write_str(if self.fired() { "Fired" } else { "No flash" })?;
+        if self.mode() != FlashMode::Unknown {
+            write!(f, ", {}", self.mode())?;
+        }
+        match self.return_light() {
+            Some(FlashReturn::NoDetection) | None => {}
+            Some(x) => write!(f, ", {}", x)?,
+        }
+        if self.red_eye_reduction() {
+            f.write_str(", Red-eye reduction")?;
+        }
+        Ok(())
+    }
+}
+
+impl IfdContext {
+    /// Display string of the enumerated tags, `None` for the others and unknown values
+    pub fn interpret(&self, item: &IFDItem) -> Option<String> {
+        let value = match item.value() {
+            Value::Short(x) => *x.first()?,
+            Value::Long(x) => u16::try_from(*x.first()?).ok()?,
+            _ => return None,
+        };
+        let result = match (self, item.tag()) {
+            (Self::Image, 0x0103) => Compression::from_u16(value)?.to_string(),
+            (Self::Image, 0x0106) => Photometric::from_u16(value)?.to_string(),
+            (Self::Image, 0x0112) => Orientation::from_u16(value)?.to_string(),
+            (Self::Image | Self::Photo, 0x8822) => ExposureProgram::from_u16(value)?.to_string(),
+            (Self::Image | Self::Photo, 0x9207) => MeteringMode::from_u16(value)?.to_string(),
+            (Self::Image | Self::Photo, 0x9208) => LightSource::from_u16(value)?.to_string(),
+            (Self::Image | Self::Photo, 0x9209) => Flash(value).to_string(),
+            (Self::Image, 0xc65a | 0xc65b | 0xcd31) => LightSource::from_u16(value)?.to_string(),
+            (Self::Photo, 0xa001) => ColorSpace::from_u16(value)?.to_string(),
+            _ => return None,
+        };
+        Some(result)
+    }
+}
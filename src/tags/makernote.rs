@@ -0,0 +1,617 @@
+use super::TagInfo;
+use crate::DataType;
+
+gen_tag_table! { CANON;
+    0x0001 CameraSettings [Short] _ "Camera settings array";
+    0x0002 FocalLength [Short] 4 "Focal length type, focal length and focal plane size";
+    0x0004 ShotInfo [Short] _ "Shot info array";
+    0x0005 Panorama [Short] _ "Panorama settings";
+    0x0006 ImageType [Ascii] _ "Image type";
+    0x0007 FirmwareVersion [Ascii] _ "Firmware version";
+    0x0008 FileNumber [Long] 1 "File number";
+    0x0009 OwnerName [Ascii] _ "Owner name";
+    0x000c SerialNumber [Long] 1 "Camera serial number";
+    0x000d CameraInfo [Long Undefined] _ "Model specific camera info";
+    0x000f CustomFunctions [Short] _ "Custom functions";
+    0x0010 ModelID [Long] 1 "Model ID";
+    0x0012 PictureInfo [Short] _ "Picture info array";
+    0x0013 ThumbnailImageValidArea [Short] 4 "Valid area of the thumbnail";
+    0x0015 SerialNumberFormat [Long] 1 "Format of the serial number";
+    0x001a SuperMacro [Short] _ "Super macro mode";
+    0x001c DateStampMode [Short] 1 "Date stamp mode";
+    0x001d MyColors [Short] _ "My colors settings";
+    0x001e FirmwareRevision [Long] 1 "Firmware revision";
+    0x0023 Categories [Long] 2 "Categories";
+    0x0024 FaceDetect1 [Short] _ "Face detection info";
+    0x0025 FaceDetect2 [Byte] _ "Face detection info";
+    0x0026 AFInfo2 [Short] _ "AF points info";
+    0x0028 ImageUniqueID [Byte] 16 "Unique ID of the image";
+    0x0081 RawDataOffset [Long] 1 "Offset of the raw data";
+    0x0083 OriginalDecisionDataOffset [Long] 1 "Offset of the original decision data";
+    0x0093 FileInfo [Short] _ "File info array";
+    0x0095 LensModel [Ascii] _ "Lens model";
+    0x0096 InternalSerialNumber [Ascii] _ "Internal serial number";
+    0x0097 DustRemovalData [Undefined] _ "Dust removal data";
+    0x0099 CustomFunctions2 [Long] _ "Custom functions of newer models";
+    0x009a AspectInfo [Long] _ "Aspect ratio and cropped size";
+    0x00a0 ProcessingInfo [Short] _ "Processing info array";
+    0x00a9 WhiteBalanceTable [Short] _ "White balance table";
+    0x00aa MeasuredColor [Short] _ "Measured color";
+    0x00b4 ColorSpace [Short] 1 "Color space, 1 for sRGB and 2 for Adobe RGB";
+    0x00b6 PreviewImageInfo [Long] _ "Preview image size and offset";
+    0x00d0 VRDOffset [Long] 1 "Offset of the recipe data";
+    0x00e0 SensorInfo [Short] _ "Sensor size and borders";
+    0x4001 ColorData [Short] _ "Model specific color data, like the white balance levels";
+    0x4002 CRWParam [Short] _ "CRW parameters";
+    0x4003 ColorInfo [Short] _ "Color info array";
+    0x4005 Flavor [Undefined] _ "Flavor";
+    0x4008 PictureStyleUserDef [Short] _ "Base styles of the user defined picture styles";
+    0x4009 PictureStylePC [Short] _ "Base styles of the PC picture styles";
+    0x4010 CustomPictureStyleFileName [Ascii] _ "File name of the custom picture style";
+    0x4013 AFMicroAdj [Long] _ "AF micro adjustment";
+    0x4015 VignettingCorr [Undefined] _ "Vignetting correction";
+    0x4016 VignettingCorr2 [Long] _ "Vignetting correction settings";
+    0x4018 LightingOpt [Long] _ "Lighting optimizer settings";
+    0x4019 LensInfo [Undefined] _ "Lens serial number";
+    0x4020 AmbienceInfo [Long] _ "Ambience selection";
+    0x4021 MultiExp [Long] _ "Multiple exposure settings";
+    0x4024 FilterInfo [Undefined] _ "Creative filter settings";
+    0x4025 HDRInfo [Long] _ "HDR settings";
+    0x4028 AFConfig [Long] _ "AF configuration";
+    0x403f RawBurstModeRoll [Long] _ "Raw burst mode info";
+}
+
+gen_tag_table! { NIKON;
+    0x0001 Version [Undefined] 4 "Makernote version";
+    0x0002 ISOSpeed [Short] 2 "ISO speed";
+    0x0003 ColorMode [Ascii] _ "Color mode";
+    0x0004 Quality [Ascii] _ "Image quality";
+    0x0005 WhiteBalance [Ascii] _ "White balance";
+    0x0006 Sharpening [Ascii] _ "Image sharpening";
+    0x0007 Focus [Ascii] _ "Focus mode";
+    0x0008 FlashSetting [Ascii] _ "Flash setting";
+    0x0009 FlashDevice [Ascii] _ "Flash device";
+    0x000b WhiteBalanceBias [SShort] _ "White balance bias";
+    0x000c WB_RBLevels [Rational] 4 "Red and blue white balance levels";
+    0x000d ProgramShift [Undefined] 4 "Program shift";
+    0x000e ExposureDiff [Undefined] 4 "Exposure difference";
+    0x000f ISOSelection [Ascii] _ "ISO selection";
+    0x0010 DataDump [Undefined] _ "Data dump";
+    0x0011 Preview [Long Ifd] 1 "Offset of the preview IFD";
+    0x0012 FlashComp [Undefined] 4 "Flash compensation";
+    0x0013 ISOSettings [Short] 2 "ISO settings";
+    0x0016 ImageBoundary [Short] 4 "Image boundary";
+    0x0017 FlashExposureComp [Undefined] 4 "Flash exposure compensation";
+    0x0018 FlashBracketComp [Undefined] 4 "Flash bracket compensation";
+    0x0019 ExposureBracketComp [SRational] 1 "Exposure bracket compensation";
+    0x001a ImageProcessing [Ascii] _ "Image processing";
+    0x001b CropHiSpeed [Short] 7 "Crop high speed";
+    0x001c ExposureTuning [Undefined] 3 "Exposure tuning";
+    0x001d SerialNumber [Ascii] _ "Serial number, also the key of the encrypted tags";
+    0x001e ColorSpace [Short] 1 "Color space";
+    0x001f VRInfo [Undefined] 8 "Vibration reduction info";
+    0x0020 ImageAuthentication [Byte] 1 "Image authentication";
+    0x0021 FaceDetect [Undefined] _ "Face detection info";
+    0x0022 ActiveDLighting [Short] 1 "Active D-Lighting";
+    0x0023 PictureControl [Undefined] _ "Picture control";
+    0x0024 WorldTime [Undefined] _ "World time";
+    0x0025 ISOInfo [Undefined] 14 "ISO info";
+    0x002a VignetteControl [Short] 1 "Vignette control";
+    0x002b DistortInfo [Undefined] _ "Distortion info";
+    0x0080 ImageAdjustment [Ascii] _ "Image adjustment";
+    0x0081 ToneComp [Ascii] _ "Tone compensation";
+    0x0082 AuxiliaryLens [Ascii] _ "Auxiliary lens";
+    0x0083 LensType [Byte] 1 "Lens type bits";
+    0x0084 Lens [Rational] 4 "Minimum and maximum focal lengths and F numbers";
+    0x0085 FocusDistance [Rational] 1 "Focus distance";
+    0x0086 DigitalZoom [Rational] 1 "Digital zoom";
+    0x0087 FlashMode [Byte] 1 "Flash mode";
+    0x0088 AFInfo [Undefined] 4 "AF info";
+    0x0089 ShootingMode [Short] 1 "Shooting mode bits";
+    0x008b LensFStops [Undefined] 4 "F stops of the lens";
+    0x008c ContrastCurve [Undefined] _ "Contrast curve";
+    0x008d ColorHue [Ascii] _ "Color hue";
+    0x008f SceneMode [Ascii] _ "Scene mode";
+    0x0090 LightSource [Ascii] _ "Light source";
+    0x0091 ShotInfo [Undefined] _ "Shot info, partly encrypted";
+    0x0092 HueAdjustment [SShort] 1 "Hue adjustment";
+    0x0093 NEFCompression [Short] 1 "NEF compression";
+    0x0094 Saturation [SShort] 1 "Saturation";
+    0x0095 NoiseReduction [Ascii] _ "Noise reduction";
+    0x0096 LinearizationTable [Undefined] _ "Linearization table";
+    0x0097 ColorBalance [Undefined] _ "Color balance, partly encrypted";
+    0x0098 LensData [Undefined] _ "Lens data, encrypted in newer versions";
+    0x0099 RawImageCenter [Short] 2 "Center of the raw image";
+    0x009a SensorPixelSize [Rational] 2 "Sensor pixel size";
+    0x009c SceneAssist [Ascii] _ "Scene assist";
+    0x009e RetouchHistory [Short] 10 "Retouch history";
+    0x00a0 SerialNO [Ascii] _ "Serial number";
+    0x00a2 ImageDataSize [Long] 1 "Image data size";
+    0x00a5 ImageCount [Long] 1 "Image count";
+    0x00a6 DeletedImageCount [Long] 1 "Deleted image count";
+    0x00a7 ShutterCount [Long] 1 "Shutter count, also the key of the encrypted tags";
+    0x00a8 FlashInfo [Undefined] _ "Flash info";
+    0x00a9 ImageOptimization [Ascii] _ "Image optimization";
+    0x00aa Saturation2 [Ascii] _ "Saturation";
+    0x00ab VariProgram [Ascii] _ "Vari program";
+    0x00ac ImageStabilization [Ascii] _ "Image stabilization";
+    0x00ad AFResponse [Ascii] _ "AF response";
+    0x00b0 MultiExposure [Undefined] _ "Multiple exposure";
+    0x00b1 HighISONoiseReduction [Short] 1 "High ISO noise reduction";
+    0x00b3 ToningEffect [Ascii] _ "Toning effect";
+    0x00b7 AFInfo2 [Undefined] _ "AF info";
+    0x00b8 FileInfo [Undefined] _ "File info";
+    0x00b9 AFTune [Undefined] _ "AF fine tune";
+    0x0e00 PrintIM [Undefined] _ "Print image matching";
+    0x0e01 CaptureData [Undefined] _ "Capture NX data";
+    0x0e09 CaptureVersion [Ascii] _ "Capture NX version";
+    0x0e0e CaptureOffsets [Undefined] _ "Capture NX offsets";
+    0x0e10 ScanIFD [Long Ifd] _ "Scan IFD";
+    0x0e1d ICCProfile [Undefined] _ "ICC profile";
+    0x0e1e CaptureOutput [Undefined] _ "Capture output";
+}
+
+gen_tag_table! { SONY;
+    0x0102 Quality [Long] 1 "Image quality";
+    0x0104 FlashExposureComp [SRational] 1 "Flash exposure compensation";
+    0x0105 Teleconverter [Long] 1 "Teleconverter";
+    0x0112 WhiteBalanceFineTune [Long] 1 "White balance fine tune";
+    0x0114 CameraSettings [Undefined] _ "Camera settings";
+    0x0115 WhiteBalance [Long] 1 "White balance";
+    0x0116 ExtraInfo [Undefined] _ "Extra info";
+    0x0e00 PrintIM [Undefined] _ "Print image matching";
+    0x1000 MultiBurstMode [Undefined] 1 "Multi burst mode";
+    0x1001 MultiBurstImageWidth [Short] 1 "Multi burst image width";
+    0x1002 MultiBurstImageHeight [Short] 1 "Multi burst image height";
+    0x1003 Panorama [Undefined] _ "Panorama";
+    0x2001 PreviewImage [Undefined] _ "Preview image";
+    0x2002 Rating [Long] 1 "Rating";
+    0x2004 Contrast [SLong] 1 "Contrast";
+    0x2005 Saturation [SLong] 1 "Saturation";
+    0x2006 Sharpness [SLong] 1 "Sharpness";
+    0x2007 Brightness [SLong] 1 "Brightness";
+    0x2008 LongExposureNoiseReduction [Long] 1 "Long exposure noise reduction";
+    0x2009 HighISONoiseReduction [Short] 1 "High ISO noise reduction";
+    0x200a HDR [Long] 1 "HDR";
+    0x200b MultiFrameNoiseReduction [Long] 1 "Multi frame noise reduction";
+    0x200e PictureEffect [Short] 1 "Picture effect";
+    0x200f SoftSkinEffect [Long] 1 "Soft skin effect";
+    0x2010 Tag2010 [Undefined] _ "Enciphered camera settings";
+    0x2011 VignettingCorrection [Long] 1 "Vignetting correction";
+    0x2012 LateralChromaticAberration [Long] 1 "Lateral chromatic aberration correction";
+    0x2013 DistortionCorrectionSetting [Long] 1 "Distortion correction";
+    0x2014 WBShiftABGM [SLong] 2 "White balance shift";
+    0x2016 AutoPortraitFramed [Short] 1 "Auto portrait framed";
+    0x201b FocusMode [Byte] 1 "Focus mode";
+    0x201c AFAreaModeSetting [Byte] 1 "AF area mode";
+    0x201d FlexibleSpotPosition [Short] 2 "Flexible spot position";
+    0x201e AFPointSelected [Byte] 1 "Selected AF point";
+    0x2020 AFPointsUsed [Byte] _ "AF points used";
+    0x2021 AFTracking [Byte] 1 "AF tracking";
+    0x2022 FocalPlaneAFPointsUsed [Byte] _ "Focal plane AF points used";
+    0x2023 MultiFrameNREffect [Long] 1 "Multi frame noise reduction effect";
+    0x2026 WBShiftABGMPrecise [SLong] 2 "Precise white balance shift";
+    0x2027 FocusLocation [Short] 4 "Focus location";
+    0x2028 VariableLowPassFilter [Short] 2 "Variable low pass filter";
+    0x2029 RAWFileType [Short] 1 "Raw file type";
+    0x202a Tag202a [Undefined] _ "Focal plane AF point info";
+    0x202b PrioritySetInAWB [Byte] 1 "Priority set in auto white balance";
+    0x202c MeteringMode2 [Short] 1 "Metering mode";
+    0x202d ExposureStandardAdjustment [SRational] 1 "Exposure standard adjustment";
+    0x202e Quality2 [Short] 2 "Image quality";
+    0x202f PixelShiftInfo [Undefined] _ "Pixel shift info";
+    0x2031 SerialNumber [Ascii] _ "Serial number";
+    0x3000 ShotInfo [Undefined] _ "Shot info";
+    0x9050 Tag9050 [Undefined] _ "Enciphered shot info, like the shutter count";
+    0x9400 Tag9400 [Undefined] _ "Enciphered sequence info";
+    0x9401 Tag9401 [Undefined] _ "Enciphered ISO info";
+    0x9402 Tag9402 [Undefined] _ "Enciphered focus info";
+    0x9403 Tag9403 [Undefined] _ "Enciphered temperature info";
+    0x9404 Tag9404 [Undefined] _ "Enciphered exposure info";
+    0x9405 Tag9405 [Undefined] _ "Enciphered lens info";
+    0x9406 Tag9406 [Undefined] _ "Enciphered battery info";
+    0x940c Tag940c [Undefined] _ "Enciphered lens mount info";
+    0x940e AFInfo [Undefined] _ "Enciphered AF info";
+    0xb000 FileFormat [Byte] 4 "File format";
+    0xb001 SonyModelID [Short] 1 "Model ID";
+    0xb020 CreativeStyle [Ascii] _ "Creative style";
+    0xb021 ColorTemperature [Long] 1 "Color temperature";
+    0xb022 ColorCompensationFilter [Long] 1 "Color compensation filter";
+    0xb023 SceneMode [Long] 1 "Scene mode";
+    0xb024 ZoneMatching [Long] 1 "Zone matching";
+    0xb025 DynamicRangeOptimizer [Long] 1 "Dynamic range optimizer";
+    0xb026 ImageStabilization [Long] 1 "Image stabilization";
+    0xb027 LensID [Long] 1 "Lens ID";
+    0xb028 MinoltaMakerNote [Long Ifd] 1 "Offset of the Minolta makernote IFD";
+    0xb029 ColorMode [Long] 1 "Color mode";
+    0xb02a LensSpec [Byte] 8 "Lens specification";
+    0xb02b FullImageSize [Long] 2 "Height and width of the full image";
+    0xb02c PreviewImageSize [Long] 2 "Height and width of the preview image";
+    0xb040 Macro [Short] 1 "Macro";
+    0xb041 ExposureMode [Short] 1 "Exposure mode";
+    0xb042 FocusMode2 [Short] 1 "Focus mode";
+    0xb043 AFMode [Short] 1 "AF mode";
+    0xb044 AFIlluminator [Short] 1 "AF illuminator";
+    0xb047 JPEGQuality [Short] 1 "JPEG quality";
+    0xb048 FlashLevel [SShort] 1 "Flash level";
+    0xb049 ReleaseMode [Short] 1 "Release mode";
+    0xb04a SequenceNumber [Short] 1 "Sequence number";
+    0xb04b AntiBlur [Short] 1 "Anti blur";
+    0xb04e FocusMode3 [Short] 1 "Focus mode";
+    0xb04f DynamicRangeOptimizer2 [Short] 1 "Dynamic range optimizer";
+    0xb050 HighISONoiseReduction2 [Short] 1 "High ISO noise reduction";
+    0xb052 IntelligentAuto [Short] 1 "Intelligent auto";
+    0xb054 WhiteBalance2 [Short] 1 "White balance";
+}
+
+gen_tag_table! { FUJIFILM;
+    0x0000 Version [Undefined] 4 "Makernote version";
+    0x0010 SerialNumber [Ascii] _ "Serial number";
+    0x1000 Quality [Ascii] _ "Image quality";
+    0x1001 Sharpness [Short] 1 "Sharpness";
+    0x1002 WhiteBalance [Short] 1 "White balance";
+    0x1003 Color [Short] 1 "Color saturation";
+    0x1004 Tone [Short] 1 "Tone";
+    0x1005 ColorTemperature [Short] 1 "Color temperature";
+    0x1006 Contrast [Short] 1 "Contrast";
+    0x100a WhiteBalanceFineTune [SLong] 2 "White balance fine tune";
+    0x100b NoiseReduction [Short] 1 "Noise reduction";
+    0x100e HighIsoNoiseReduction [Short] 1 "High ISO noise reduction";
+    0x1010 FlashMode [Short] 1 "Flash mode";
+    0x1011 FlashStrength [SRational] 1 "Flash strength";
+    0x1020 Macro [Short] 1 "Macro";
+    0x1021 FocusMode [Short] 1 "Focus mode";
+    0x1022 AFMode [Short] 1 "AF mode";
+    0x1023 FocusPixel [Short] 2 "Focus pixel";
+    0x1030 SlowSync [Short] 1 "Slow sync";
+    0x1031 PictureMode [Short] 1 "Picture mode";
+    0x1032 ExposureCount [Short] 1 "Exposure count";
+    0x1033 EXRAuto [Short] 1 "EXR auto";
+    0x1034 EXRMode [Short] 1 "EXR mode";
+    0x1040 ShadowTone [SLong] 1 "Shadow tone";
+    0x1041 HighlightTone [SLong] 1 "Highlight tone";
+    0x1044 DigitalZoom [Long] 1 "Digital zoom";
+    0x1045 LensModulationOptimizer [Long] 1 "Lens modulation optimizer";
+    0x1047 GrainEffectRoughness [SLong] 1 "Grain effect roughness";
+    0x1048 ColorChromeEffect [SLong] 1 "Color chrome effect";
+    0x1049 BWAdjustment [SByte] 1 "Black and white warm or cool adjustment";
+    0x104b BWMagentaGreen [SByte] 1 "Black and white magenta or green adjustment";
+    0x104c GrainEffectSize [Short] 1 "Grain effect size";
+    0x104d CropMode [Short] 1 "Crop mode";
+    0x104e ColorChromeFXBlue [SLong] 1 "Color chrome FX blue";
+    0x1050 ShutterType [Short] 1 "Shutter type";
+    0x1100 AutoBracketing [Short] 1 "Auto bracketing";
+    0x1101 SequenceNumber [Short] 1 "Sequence number";
+    0x1103 DriveSettings [Long] 1 "Drive settings";
+    0x1153 PanoramaAngle [Short] 1 "Panorama angle";
+    0x1154 PanoramaDirection [Short] 1 "Panorama direction";
+    0x1201 AdvancedFilter [Long] 1 "Advanced filter";
+    0x1210 ColorMode [Short] 1 "Color mode";
+    0x1300 BlurWarning [Short] 1 "Blur warning";
+    0x1301 FocusWarning [Short] 1 "Focus warning";
+    0x1302 ExposureWarning [Short] 1 "Exposure warning";
+    0x1304 GEImageSize [Ascii] _ "Image size";
+    0x1400 DynamicRange [Short] 1 "Dynamic range";
+    0x1401 FilmMode [Short] 1 "Film simulation";
+    0x1402 DynamicRangeSetting [Short] 1 "Dynamic range setting";
+    0x1403 DevelopmentDynamicRange [Short] 1 "Development dynamic range";
+    0x1404 MinFocalLength [Rational] 1 "Minimum focal length of the lens";
+    0x1405 MaxFocalLength [Rational] 1 "Maximum focal length of the lens";
+    0x1406 MaxApertureAtMinFocal [Rational] 1 "Maximum aperture at the minimum focal length";
+    0x1407 MaxApertureAtMaxFocal [Rational] 1 "Maximum aperture at the maximum focal length";
+    0x140b AutoDynamicRange [Short] 1 "Auto dynamic range";
+    0x1422 ImageStabilization [Short] 3 "Image stabilization";
+    0x1431 Rating [Long] 1 "Rating";
+    0x1436 ImageGeneration [Short] 1 "Original or re-developed image";
+    0x1438 ImageCount [Short] 1 "Image count";
+    0x1443 DRangePriority [Short] 1 "Dynamic range priority";
+    0x1444 DRangePriorityAuto [Short] 1 "Auto dynamic range priority";
+    0x1445 DRangePriorityFixed [Short] 1 "Fixed dynamic range priority";
+    0x1446 FlickerReduction [Long] 1 "Flicker reduction";
+    0x3803 VideoRecordingMode [Short] 1 "Video recording mode";
+    0x4100 FacesDetected [Short] 1 "Number of detected faces";
+    0x4103 FacePositions [Short] _ "Positions of the detected faces";
+    0x4200 NumFaceElements [Short] 1 "Number of face elements";
+    0x8000 FileSource [Ascii] _ "File source";
+    0x8002 OrderNumber [Long] 1 "Order number";
+    0x8003 FrameNumber [Short] 1 "Frame number";
+    0xb211 Parallax [SRational] 1 "Parallax of multi picture images";
+}
+
+gen_tag_table! { OLYMPUS;
+    0x0000 MakerNoteVersion [Undefined] 4 "Makernote version";
+    0x0001 MinoltaCameraSettingsOld [Long] _ "Old Minolta camera settings";
+    0x0003 MinoltaCameraSettings [Long] _ "Minolta camera settings";
+    0x0040 CompressedImageSize [Long] 1 "Compressed image size";
+    0x0081 PreviewImageData [Undefined] _ "Preview image data";
+    0x0088 PreviewImageStart [Long] 1 "Offset of the preview image";
+    0x0089 PreviewImageLength [Long] 1 "Bytes of the preview image";
+    0x0100 ThumbnailImage [Undefined] _ "Thumbnail image";
+    0x0104 BodyFirmwareVersion [Long] 1 "Body firmware version";
+    0x0200 SpecialMode [Long] 3 "Special mode";
+    0x0201 Quality [Short] 1 "Image quality";
+    0x0202 Macro [Short] 1 "Macro";
+    0x0203 BWMode [Short] 1 "Black and white mode";
+    0x0204 DigitalZoom [Rational] 1 "Digital zoom";
+    0x0205 FocalPlaneDiagonal [Rational] 1 "Focal plane diagonal";
+    0x0206 LensDistortionParams [SShort] 6 "Lens distortion parameters";
+    0x0207 CameraType [Ascii] _ "Camera type";
+    0x0208 PictureInfo [Ascii] _ "Picture info";
+    0x0209 CameraID [Undefined] _ "Camera ID";
+    0x020b ImageWidth [Long] 1 "Image width";
+    0x020c ImageHeight [Long] 1 "Image height";
+    0x020d Software [Ascii] _ "Software";
+    0x0280 PreviewImage [Byte] _ "Preview image";
+    0x0300 PreCaptureFrames [Short] 1 "Pre-capture frames";
+    0x0301 WhiteBoard [Short] 1 "White board";
+    0x0302 OneTouchWB [Short] 1 "One touch white balance";
+    0x0303 WhiteBalanceBracket [Short] 1 "White balance bracket";
+    0x0304 WhiteBalanceBias [Short] 1 "White balance bias";
+    0x0403 SceneMode [Short] 1 "Scene mode";
+    0x0404 SerialNumber [Ascii] _ "Serial number";
+    0x0405 Firmware [Ascii] _ "Firmware";
+    0x0e00 PrintIM [Undefined] _ "Print image matching";
+    0x0f00 DataDump1 [Undefined] _ "Data dump";
+    0x0f01 DataDump2 [Undefined] _ "Data dump";
+    0x1000 ShutterSpeed [SRational] 1 "Shutter speed";
+    0x1001 ISOSpeed [SRational] 1 "ISO speed";
+    0x1002 ApertureValue [SRational] 1 "Aperture";
+    0x1003 Brightness [SRational] 1 "Brightness";
+    0x1004 FlashMode [Short] 1 "Flash mode";
+    0x1005 FlashDevice [Short] 2 "Flash device";
+    0x1006 Bracket [SRational] 1 "Exposure bracket";
+    0x1007 SensorTemperature [SShort] 1 "Sensor temperature";
+    0x1008 LensTemperature [SShort] 1 "Lens temperature";
+    0x100b FocusMode [Short] 1 "Focus mode";
+    0x100c FocusDistance [Rational] 1 "Focus distance";
+    0x100d Zoom [Short] 1 "Zoom";
+    0x100e MacroFocus [Short] 1 "Macro focus";
+    0x100f SharpnessFactor [Short] 1 "Sharpness factor";
+    0x1010 FlashChargeLevel [Short] 1 "Flash charge level";
+    0x1011 ColorMatrix [Short] 9 "Color matrix";
+    0x1012 BlackLevel [Short] 4 "Black level";
+    0x1015 WhiteBalance [Short] 2 "White balance";
+    0x1017 RedBalance [Short] 2 "Red balance";
+    0x1018 BlueBalance [Short] 2 "Blue balance";
+    0x101a SerialNumber2 [Ascii] 32 "Serial number";
+    0x1023 FlashBias [SRational] 1 "Flash bias";
+    0x1029 Contrast [Short] 1 "Contrast";
+    0x1034 CompressionRatio [Rational] 1 "Compression ratio";
+    0x1038 AFResult [Short] 1 "AF result";
+    0x1039 CCDScanMode [Short] 1 "CCD scan mode";
+    0x103a NoiseReduction [Short] 1 "Noise reduction";
+    0x103b InfinityLensStep [Short] 1 "Infinity lens step";
+    0x103c NearLensStep [Short] 1 "Near lens step";
+    0x2010 Equipment [Undefined Ifd] _ "Equipment IFD";
+    0x2020 CameraSettings [Undefined Ifd] _ "Camera settings IFD";
+    0x2030 RawDevelopment [Undefined Ifd] _ "Raw development IFD";
+    0x2031 RawDevelopment2 [Undefined Ifd] _ "Raw development IFD of newer models";
+    0x2040 ImageProcessing [Undefined Ifd] _ "Image processing IFD";
+    0x2050 FocusInfo [Undefined Ifd] _ "Focus info IFD";
+    0x3000 RawInfo [Undefined Ifd] _ "Raw info IFD";
+}
+
+gen_tag_table! { PANASONIC;
+    0x0001 Quality [Short] 1 "Image quality";
+    0x0002 FirmwareVersion [Undefined] 4 "Firmware version";
+    0x0003 WhiteBalance [Short] 1 "White balance";
+    0x0007 FocusMode [Short] 1 "Focus mode";
+    0x000f AFMode [Byte] 2 "AF mode";
+    0x001a ImageStabilization [Short] 1 "Image stabilization";
+    0x001c Macro [Short] 1 "Macro";
+    0x001f ShootingMode [Short] 1 "Shooting mode";
+    0x0020 Audio [Short] 1 "Audio";
+    0x0021 DataDump [Undefined] _ "Data dump";
+    0x0023 WhiteBalanceBias [SShort] 1 "White balance bias";
+    0x0024 FlashBias [SShort] 1 "Flash bias";
+    0x0025 InternalSerialNumber [Undefined] 16 "Internal serial number";
+    0x0026 ExifVersion [Undefined] 4 "Makernote version";
+    0x0028 ColorEffect [Short] 1 "Color effect";
+    0x0029 TimeSincePowerOn [Long] 1 "Time since power on in 1/100 seconds";
+    0x002a BurstMode [Short] 1 "Burst mode";
+    0x002b SequenceNumber [Long] 1 "Sequence number";
+    0x002c ContrastMode [Short] 1 "Contrast mode";
+    0x002d NoiseReduction [Short] 1 "Noise reduction";
+    0x002e SelfTimer [Short] 1 "Self timer";
+    0x0030 Rotation [Short] 1 "Rotation";
+    0x0031 AFAssistLamp [Short] 1 "AF assist lamp";
+    0x0032 ColorMode [Short] 1 "Color mode";
+    0x0033 BabyAge1 [Ascii] _ "Baby age";
+    0x0034 OpticalZoomMode [Short] 1 "Optical zoom mode";
+    0x0035 ConversionLens [Short] 1 "Conversion lens";
+    0x0036 TravelDay [Short] 1 "Travel day";
+    0x0039 Contrast [Short] 1 "Contrast";
+    0x003a WorldTimeLocation [Short] 1 "World time location";
+    0x003b TextStamp1 [Short] 1 "Text stamp";
+    0x003c ProgramISO [Short] 1 "Program ISO";
+    0x003d AdvancedSceneMode [Short] 1 "Advanced scene mode";
+    0x003e TextStamp2 [Short] 1 "Text stamp";
+    0x003f FacesDetected [Short] 1 "Number of detected faces";
+    0x0040 Saturation [Short] 1 "Saturation";
+    0x0041 Sharpness [Short] 1 "Sharpness";
+    0x0042 FilmMode [Short] 1 "Film mode";
+    0x0044 ColorTempKelvin [Short] 1 "Color temperature in Kelvin";
+    0x0045 BracketSettings [Short] 1 "Bracket settings";
+    0x0046 WBAdjustAB [Short] 1 "White balance adjustment in amber and blue";
+    0x0047 WBAdjustGM [Short] 1 "White balance adjustment in green and magenta";
+    0x0048 FlashCurtain [Short] 1 "Flash curtain";
+    0x0049 LongExposureNoiseReduction [Short] 1 "Long exposure noise reduction";
+    0x004b PanasonicImageWidth [Long] 1 "Image width";
+    0x004c PanasonicImageHeight [Long] 1 "Image height";
+    0x004d AFPointPosition [Rational] 2 "AF point position";
+    0x004e FaceDetInfo [Undefined] _ "Face detection info";
+    0x0051 LensType [Ascii] _ "Lens type";
+    0x0052 LensSerialNumber [Ascii] _ "Lens serial number";
+    0x0053 AccessoryType [Ascii] _ "Accessory type";
+    0x0054 AccessorySerialNumber [Ascii] _ "Accessory serial number";
+    0x0059 Transform [Undefined] 4 "Transform";
+    0x005d IntelligentExposure [Short] 1 "Intelligent exposure";
+    0x0060 LensFirmwareVersion [Undefined] 4 "Lens firmware version";
+    0x0061 FaceRecInfo [Undefined] _ "Face recognition info";
+    0x0062 FlashWarning [Short] 1 "Flash warning";
+    0x0065 Title [Undefined] _ "Title";
+    0x0066 BabyName [Undefined] _ "Baby name";
+    0x0067 Location [Undefined] _ "Location";
+    0x0069 Country [Undefined] _ "Country";
+    0x006b State [Undefined] _ "State";
+    0x006d City [Undefined] _ "City";
+    0x006f Landmark [Undefined] _ "Landmark";
+    0x0070 IntelligentResolution [Byte] 1 "Intelligent resolution";
+    0x0077 BurstSpeed [Short] 1 "Burst speed";
+    0x0079 IntelligentDRange [Short] 1 "Intelligent dynamic range";
+    0x007c ClearRetouch [Short] 1 "Clear retouch";
+    0x0080 City2 [Undefined] _ "City";
+    0x0086 ManometerPressure [Short] 1 "Manometer pressure";
+    0x0089 PhotoStyle [Short] 1 "Photo style";
+    0x008a ShadingCompensation [Short] 1 "Shading compensation";
+    0x008c AccelerometerZ [Short] 1 "Accelerometer Z";
+    0x008d AccelerometerX [Short] 1 "Accelerometer X";
+    0x008e AccelerometerY [Short] 1 "Accelerometer Y";
+    0x008f CameraOrientation [Byte] 1 "Camera orientation";
+    0x0090 RollAngle [Short] 1 "Roll angle";
+    0x0091 PitchAngle [Short] 1 "Pitch angle";
+    0x0093 SweepPanoramaDirection [Byte] 1 "Sweep panorama direction";
+    0x0094 SweepPanoramaFieldOfView [Short] 1 "Sweep panorama field of view";
+    0x0096 TimerRecording [Byte] 1 "Timer recording";
+    0x009d InternalNDFilter [Rational] 1 "Internal ND filter";
+    0x009e HDR [Short] 1 "HDR";
+    0x009f ShutterType [Short] 1 "Shutter type";
+    0x00a3 ClearRetouchValue [Rational] 1 "Clear retouch value";
+    0x00ab TouchAE [Short] 1 "Touch AE";
+    0x0e00 PrintIM [Undefined] _ "Print image matching";
+    0x8000 MakerNoteVersion [Undefined] 4 "Makernote version";
+    0x8001 SceneMode [Short] 1 "Scene mode";
+    0x8004 WBRedLevel [Short] 1 "White balance red level";
+    0x8005 WBGreenLevel [Short] 1 "White balance green level";
+    0x8006 WBBlueLevel [Short] 1 "White balance blue level";
+    0x8007 FlashFired [Short] 1 "Flash fired";
+    0x8008 TextStamp3 [Short] 1 "Text stamp";
+    0x8009 TextStamp4 [Short] 1 "Text stamp";
+    0x8010 BabyAge2 [Ascii] _ "Baby age";
+    0x8012 Transform2 [Undefined] 4 "Transform";
+}
+
+gen_tag_table! { PENTAX;
+    0x0000 Version [Byte] 4 "Makernote version";
+    0x0001 Mode [Short] 1 "Shooting mode";
+    0x0002 PreviewResolution [Short] 2 "Preview resolution";
+    0x0003 PreviewLength [Long] 1 "Bytes of the preview image";
+    0x0004 PreviewOffset [Long] 1 "Offset of the preview image";
+    0x0005 ModelID [Long] 1 "Model ID";
+    0x0006 Date [Undefined] 4 "Date";
+    0x0007 Time [Undefined] 3 "Time";
+    0x0008 Quality [Short] 1 "Image quality";
+    0x0009 Size [Short] 1 "Image size";
+    0x000c Flash [Short] _ "Flash";
+    0x000d Focus [Short] 1 "Focus mode";
+    0x000e AFPoint [Short] 1 "Selected AF point";
+    0x000f AFPointInFocus [Long] 1 "AF point in focus";
+    0x0012 ExposureTime [Long] 1 "Exposure time in 1/100000 seconds";
+    0x0013 FNumber [Short] 1 "F number times 10";
+    0x0014 ISO [Short] 1 "ISO index";
+    0x0016 ExposureCompensation [Short] 1 "Exposure compensation";
+    0x0017 MeteringMode [Short] 1 "Metering mode";
+    0x0018 AutoBracketing [Short] 1 "Auto bracketing";
+    0x0019 WhiteBalance [Short] 1 "White balance";
+    0x001a WhiteBalanceMode [Short] 1 "White balance mode";
+    0x001b BlueBalance [Short] 1 "Blue balance";
+    0x001c RedBalance [Short] 1 "Red balance";
+    0x001d FocalLength [Long] 1 "Focal length in 1/100 mm";
+    0x001e DigitalZoom [Short] 1 "Digital zoom";
+    0x001f Saturation [Short] 1 "Saturation";
+    0x0020 Contrast [Short] 1 "Contrast";
+    0x0021 Sharpness [Short] 1 "Sharpness";
+    0x0022 Location [Short] 1 "Home town or destination";
+    0x0023 Hometown [Short] 1 "Home town";
+    0x0024 Destination [Short] 1 "Destination";
+    0x0025 HometownDST [Short] 1 "Daylight saving time of the home town";
+    0x0026 DestinationDST [Short] 1 "Daylight saving time of the destination";
+    0x0027 DSPFirmwareVersion [Undefined] 4 "DSP firmware version";
+    0x0028 CPUFirmwareVersion [Undefined] 4 "CPU firmware version";
+    0x0029 FrameNumber [Long] 1 "Frame number";
+    0x002d EffectiveLV [Short] 1 "Effective light value";
+    0x0032 ImageProcessing [Undefined] 4 "Image processing";
+    0x0033 PictureMode [Byte] 3 "Picture mode";
+    0x0034 DriveMode [Byte] 4 "Drive mode";
+    0x0037 ColorSpace [Short] 1 "Color space";
+    0x0038 ImageAreaOffset [Short] 2 "Image area offset";
+    0x0039 RawImageSize [Short] 2 "Raw image size";
+    0x003c AFPointsInFocus [Short] 1 "AF points in focus";
+    0x003e PreviewImageBorders [Byte] 4 "Preview image borders";
+    0x003f LensType [Byte] 2 "Lens type";
+    0x0040 SensitivityAdjust [Short] 1 "Sensitivity adjustment";
+    0x0041 ImageProcessingCount [Short] 1 "Image processing count";
+    0x0047 CameraTemperature [SByte] 1 "Camera temperature";
+    0x0048 AELock [Short] 1 "AE lock";
+    0x0049 NoiseReduction [Short] 1 "Noise reduction";
+    0x004d FlashExposureComp [SLong] 1 "Flash exposure compensation";
+    0x004f ImageTone [Short] 1 "Image tone";
+    0x0050 ColorTemperature [Short] 1 "Color temperature";
+    0x005c ShakeReduction [Undefined] 4 "Shake reduction";
+    0x005d ShutterCount [Undefined] 4 "Shutter count";
+    0x0069 DynamicRangeExpansion [Undefined] 4 "Dynamic range expansion";
+    0x0071 HighISONoiseReduction [Byte] _ "High ISO noise reduction";
+    0x0072 AFAdjustment [SShort] 1 "AF adjustment";
+    0x0200 BlackPoint [Short] 4 "Black point";
+    0x0201 WhitePoint [Short] 4 "White point";
+    0x0205 ShotInfo [Undefined] _ "Shot info";
+    0x0206 AEInfo [Undefined] _ "AE info";
+    0x0207 LensInfo [Undefined] _ "Lens info";
+    0x0208 FlashInfo [Undefined] _ "Flash info";
+    0x0209 AEMeteringSegments [Byte] _ "AE metering segments";
+    0x020a FlashADump [Byte] _ "Flash data dump";
+    0x020b FlashBDump [Byte] _ "Flash data dump";
+    0x020d WB_RGGBLevelsDaylight [Short] 4 "RGGB white balance levels in daylight";
+    0x020e WB_RGGBLevelsShade [Short] 4 "RGGB white balance levels in shade";
+    0x020f WB_RGGBLevelsCloudy [Short] 4 "RGGB white balance levels in cloudy light";
+    0x0210 WB_RGGBLevelsTungsten [Short] 4 "RGGB white balance levels in tungsten light";
+    0x0211 WB_RGGBLevelsFluorescentD [Short] 4 "RGGB white balance levels in daylight fluorescent light";
+    0x0212 WB_RGGBLevelsFluorescentN [Short] 4 "RGGB white balance levels in neutral fluorescent light";
+    0x0213 WB_RGGBLevelsFluorescentW [Short] 4 "RGGB white balance levels in white fluorescent light";
+    0x0214 WB_RGGBLevelsFlash [Short] 4 "RGGB white balance levels with flash";
+    0x0215 CameraInfo [Long] _ "Camera info";
+    0x0216 BatteryInfo [Undefined] _ "Battery info";
+    0x021f AFInfo [Undefined] _ "AF info";
+    0x0222 ColorInfo [Undefined] _ "Color info";
+    0x0229 SerialNumber [Ascii] _ "Serial number";
+    0x03fe DataDump [Undefined] _ "Data dump";
+}
+
+gen_tag_table! { SAMSUNG;
+    0x0001 MakerNoteVersion [Undefined] 4 "Makernote version";
+    0x0002 DeviceType [Long] 1 "Device type";
+    0x0003 SamsungModelID [Long] 1 "Model ID";
+    0x0020 SmartAlbumColor [Long] 2 "Smart album color";
+    0x0021 PictureWizard [Short] 5 "Picture wizard";
+    0x0030 LocalLocationName [Undefined] _ "Local location name";
+    0x0035 PreviewIFD [Long Ifd] 1 "Offset of the preview IFD";
+    0x0040 RawDataByteOrder [Long] 1 "Byte order of the raw data";
+    0x0041 WhiteBalanceSetup [Long] 1 "White balance setup";
+    0x0043 CameraTemperature [SRational] 1 "Camera temperature";
+    0x0050 RawDataCFAPattern [Long] 1 "CFA pattern of the raw data";
+    0x0100 FaceDetect [Short] 1 "Face detection";
+    0x0120 FaceRecognition [Long] 1 "Face recognition";
+    0x0123 FaceName [Undefined] _ "Face name";
+    0xa001 FirmwareName [Ascii] _ "Firmware name";
+    0xa003 LensType [Short] _ "Lens type";
+    0xa004 LensFirmware [Ascii] _ "Lens firmware";
+    0xa005 InternalLensSerialNumber [Ascii] _ "Internal lens serial number";
+    0xa010 SensorAreas [Long] 8 "Sensor areas";
+    0xa011 ColorSpace [Short] 1 "Color space";
+    0xa012 SmartRange [Short] 1 "Smart range";
+    0xa013 ExposureCompensation [SRational] 1 "Exposure compensation";
+    0xa014 ISO [Long] 1 "ISO";
+    0xa018 ExposureTime [Rational] 1 "Exposure time";
+    0xa019 FNumber [Rational] 1 "F number";
+    0xa01a FocalLengthIn35mmFormat [Long] 1 "Focal length in 35mm format";
+    0xa020 EncryptionKey [Long] 11 "Encryption key";
+    0xa021 WB_RGGBLevelsUncorrected [Long] 4 "Uncorrected RGGB white balance levels";
+    0xa022 WB_RGGBLevelsAuto [Long] 4 "Auto RGGB white balance levels";
+    0xa023 WB_RGGBLevelsIlluminator1 [Long] 4 "RGGB white balance levels under illuminator 1";
+    0xa024 WB_RGGBLevelsIlluminator2 [Long] 4 "RGGB white balance levels under illuminator 2";
+    0xa028 WB_RGGBLevelsBlack [Long] 4 "RGGB black levels";
+    0xa030 ColorMatrix [SLong] 9 "Color matrix";
+    0xa031 ColorMatrixSRGB [SLong] 9 "Color matrix to sRGB";
+    0xa032 ColorMatrixAdobeRGB [SLong] 9 "Color matrix to Adobe RGB";
+    0xa040 ToneCurve [Long] _ "Tone curve";
+}
@@ -0,0 +1,102 @@
+use super::TagInfo;
+use crate::DataType;
+
+gen_tag_table! { TAGS;
+    0x829a ExposureTime [Rational] 1 "Exposure time in seconds";
+    0x829d FNumber [Rational] 1 "The F number";
+    0x8822 ExposureProgram [Short] 1 "Program used to set the exposure";
+    0x8824 SpectralSensitivity [Ascii] _ "Spectral sensitivity of each channel";
+    0x8827 ISOSpeedRatings [Short] _ "ISO speed, named PhotographicSensitivity since Exif 2.3";
+    0x8828 OECF [Undefined] _ "Opto-electric conversion function";
+    0x8830 SensitivityType [Short] 1 "Which of the sensitivity tags the ISO speed is";
+    0x8831 StandardOutputSensitivity [Long] 1 "Standard output sensitivity of ISO 12232";
+    0x8832 RecommendedExposureIndex [Long] 1 "Recommended exposure index of ISO 12232";
+    0x8833 ISOSpeed [Long] 1 "ISO speed of ISO 12232";
+    0x8834 ISOSpeedLatitudeyyy [Long] 1 "ISO speed latitude yyy of ISO 12232";
+    0x8835 ISOSpeedLatitudezzz [Long] 1 "ISO speed latitude zzz of ISO 12232";
+    0x9000 ExifVersion [Undefined] 4 "Exif version, like 0232";
+    0x9003 DateTimeOriginal [Ascii] 20 "When the original image was taken";
+    0x9004 DateTimeDigitized [Ascii] 20 "When the image was stored as digital data";
+    0x9010 OffsetTime [Ascii] 7 "UTC offset of DateTime, like +09:00";
+    0x9011 OffsetTimeOriginal [Ascii] 7 "UTC offset of DateTimeOriginal";
+    0x9012 OffsetTimeDigitized [Ascii] 7 "UTC offset of DateTimeDigitized";
+    0x9101 ComponentsConfiguration [Undefined] 4 "Order of the components";
+    0x9102 CompressedBitsPerPixel [Rational] 1 "Compression mode in bits per pixel";
+    0x9201 ShutterSpeedValue [SRational] 1 "Shutter speed in APEX";
+    0x9202 ApertureValue [Rational] 1 "Aperture in APEX";
+    0x9203 BrightnessValue [SRational] 1 "Brightness in APEX";
+    0x9204 ExposureBiasValue [SRational] 1 "Exposure bias in APEX";
+    0x9205 MaxApertureValue [Rational] 1 "Smallest F number of the lens in APEX";
+    0x9206 SubjectDistance [Rational] 1 "Distance to the subject in meters";
+    0x9207 MeteringMode [Short] 1 "Metering mode";
+    0x9208 LightSource [Short] 1 "Kind of light source";
+    0x9209 Flash [Short] 1 "Flash status bits";
+    0x920a FocalLength [Rational] 1 "Actual focal length of the lens in mm";
+    0x9214 SubjectArea [Short] _ "Location and area of the main subject";
+    0x927c MakerNote [Undefined] _ "Manufacturer specific data";
+    0x9286 UserComment [Undefined] _ "User comment, with a charset prefix";
+    0x9290 SubSecTime [Ascii] _ "Fractions of seconds of DateTime";
+    0x9291 SubSecTimeOriginal [Ascii] _ "Fractions of seconds of DateTimeOriginal";
+    0x9292 SubSecTimeDigitized [Ascii] _ "Fractions of seconds of DateTimeDigitized";
+    0x9400 Temperature [SRational] 1 "Ambient temperature in degrees Celsius";
+    0x9401 Humidity [Rational] 1 "Ambient humidity in percent";
+    0x9402 Pressure [Rational] 1 "Air pressure in hPa";
+    0x9403 WaterDepth [SRational] 1 "Water depth in meters";
+    0x9404 Acceleration [Rational] 1 "Acceleration in mGal";
+    0x9405 CameraElevationAngle [SRational] 1 "Elevation angle of the camera in degrees";
+    0xa000 FlashpixVersion [Undefined] 4 "Supported Flashpix version";
+    0xa001 ColorSpace [Short] 1 "1 for sRGB, 0xffff for uncalibrated";
+    0xa002 PixelXDimension [Short Long] 1 "Valid width of the image";
+    0xa003 PixelYDimension [Short Long] 1 "Valid height of the image";
+    0xa004 RelatedSoundFile [Ascii] 13 "Name of the related audio file";
+    0xa005 InteroperabilityTag [Long Ifd] 1 "Offset of the interoperability IFD";
+    0xa20b FlashEnergy [Rational] 1 "Strobe energy in BCPS";
+    0xa20c SpatialFrequencyResponse [Undefined] _ "Spatial frequency table of ISO 12233";
+    0xa20e FocalPlaneXResolution [Rational] 1 "Pixels per FocalPlaneResolutionUnit in width";
+    0xa20f FocalPlaneYResolution [Rational] 1 "Pixels per FocalPlaneResolutionUnit in height";
+    0xa210 FocalPlaneResolutionUnit [Short] 1 "Unit of the focal plane resolutions";
+    0xa214 SubjectLocation [Short] 2 "Location of the main subject";
+    0xa215 ExposureIndex [Rational] 1 "Selected exposure index";
+    0xa217 SensingMethod [Short] 1 "Image sensor type";
+    0xa300 FileSource [Undefined] 1 "Source of the image, 3 for digital cameras";
+    0xa301 SceneType [Undefined] 1 "1 for directly photographed images";
+    0xa302 CFAPattern [Undefined] _ "Color filter array geometry";
+    0xa401 CustomRendered [Short] 1 "Special processing on the image data";
+    0xa402 ExposureMode [Short] 1 "Auto, manual or auto bracket exposure";
+    0xa403 WhiteBalance [Short] 1 "Auto or manual white balance";
+    0xa404 DigitalZoomRatio [Rational] 1 "Digital zoom ratio, 0 when not used";
+    0xa405 FocalLengthIn35mmFilm [Short] 1 "Equivalent focal length for 35mm film";
+    0xa406 SceneCaptureType [Short] 1 "Type of the scene";
+    0xa407 GainControl [Short] 1 "Degree of the overall gain adjustment";
+    0xa408 Contrast [Short] 1 "Contrast processing";
+    0xa409 Saturation [Short] 1 "Saturation processing";
+    0xa40a Sharpness [Short] 1 "Sharpness processing";
+    0xa40b DeviceSettingDescription [Undefined] _ "Picture taking conditions of a camera model";
+    0xa40c SubjectDistanceRange [Short] 1 "Range of the subject distance";
+    0xa420 ImageUniqueID [Ascii] 33 "Unique ID as a 128 bit hex string";
+    0xa430 CameraOwnerName [Ascii] _ "Owner of the camera";
+    0xa431 BodySerialNumber [Ascii] _ "Serial number of the camera body";
+    0xa432 LensSpecification [Rational] 4 "Minimum and maximum focal lengths and F numbers";
+    0xa433 LensMake [Ascii] _ "Manufacturer of the lens";
+    0xa434 LensModel [Ascii] _ "Model of the lens";
+    0xa435 LensSerialNumber [Ascii] _ "Serial number of the lens";
+    0xa436 ImageTitle [Ascii] _ "Title of the image";
+    0xa437 Photographer [Ascii] _ "Name of the photographer";
+    0xa438 ImageEditor [Ascii] _ "Name of the main editor";
+    0xa439 CameraFirmware [Ascii] _ "Firmware of the camera";
+    0xa43a RAWDevelopingSoftware [Ascii] _ "Software used to develop the raw image";
+    0xa43b ImageEditingSoftware [Ascii] _ "Software used to edit the image";
+    0xa43c MetadataEditingSoftware [Ascii] _ "Software used to edit the metadata";
+    0xa460 CompositeImage [Short] 1 "Whether the image is a composite image";
+    0xa461 SourceImageNumberOfCompositeImage [Short] 2 "Number of source images of the composite image";
+    0xa462 SourceExposureTimesOfCompositeImage [Undefined] _ "Exposure times of the source images";
+    0xa500 Gamma [Rational] 1 "Gamma coefficient";
+}
+
+gen_tag_table! { IOP_TAGS;
+    0x0001 InteroperabilityIndex [Ascii] _ "Interoperability rule, like R98";
+    0x0002 InteroperabilityVersion [Undefined] 4 "Interoperability version";
+    0x1000 RelatedImageFileFormat [Ascii] _ "File format of the image";
+    0x1001 RelatedImageWidth [Short Long] 1 "Width of the image";
+    0x1002 RelatedImageLength [Short Long] 1 "Height of the image";
+}
@@ -0,0 +1,103 @@
+use std::fmt;
+
+/// How the bytes of a string were decoded
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Encoding {
+    Ascii,
+    Utf8,
+    /// Bytes that are not valid UTF-8, mapped one to one to U+0000..U+00FF
+    Latin1,
+    /// UTF-16 in little endian, like the Windows XP tags
+    Ucs2Le,
+    Ucs2Be,
+}
+
+/// A decoded string and the encoding it was stored in
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Text {
+    pub text: String,
+    pub encoding: Encoding,
+}
+
+impl fmt::Display for Text {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+fn is_padding(x: char) -> bool {
+    x == '\0' || x.is_whitespace()
+}
+
+/// `None` for blank strings
+fn finish(text: String, encoding: Encoding) -> Option<Text> {
+    let trimmed = text.trim_matches(is_padding);
+    if trimmed.is_empty() {
+        return None;
+    }
+    let text = if trimmed.len() == text.len() {
+        text
+    } else {
+        trimmed.to_owned()
+    };
+    Some(Text { text, encoding })
+}
+
+/// Cuts at the first NUL and tries ASCII, UTF-8 and then Latin-1
+pub(crate) fn decode_bytes(data: &[u8]) -> Option<Text> {
+    let end = data.iter().position(|&x| x == 0).unwrap_or(data.len());
+    let data = &data[..end];
+
+    if data.is_ascii() {
+        return finish(String::from_utf8_lossy(data).into_owned(), Encoding::Ascii);
+    }
+    match std::str::from_utf8(data) {
+        Ok(x) => finish(x.to_owned(), Encoding::Utf8),
+        Err(_) => finish(data.iter().map(|&x| x as char).collect(), Encoding::Latin1),
+    }
+}
+
+/// Cuts at the first NUL character, a BOM overrides `is_le`
+pub(crate) fn decode_ucs2(data: &[u8], mut is_le: bool) -> Option<Text> {
+    let mut data = data;
+    match data {
+        [0xff, 0xfe, rest @ ..] => (data, is_le) = (rest, true),
+        [0xfe, 0xff, rest @ ..] => (data, is_le) = (rest, false),
+        _ => {}
+    }
+
+    let units = data
+        .chunks_exact(2)
+        .map(|x| {
+            if is_le {
+                u16::from_le_bytes([x[0], x[1]])
+            } else {
+                u16::from_be_bytes([x[0], x[1]])
+            }
+        })
+        .take_while(|&x| x != 0);
+    let text = char::decode_utf16(units)
+        .map(|x| x.unwrap_or(char::REPLACEMENT_CHARACTER))
+        .collect();
+
+    let encoding = if is_le {
+        Encoding::Ucs2Le
+    } else {
+        Encoding::Ucs2Be
+    };
+    finish(text, encoding)
+}
+
+/// UserComment, GPSProcessingMethod and GPSAreaInformation start with an 8 bytes
+/// charset id. The UNICODE ones are in the byte order of the file.
+///
+/// JIS strings are not decoded. Strings without a known id are decoded as a whole,
+/// since some writers leave it out.
+pub(crate) fn decode_prefixed(data: &[u8], is_le: bool) -> Option<Text> {
+    match data.split_at_checked(8) {
+        Some((b"ASCII\0\0\0" | [0, 0, 0, 0, 0, 0, 0, 0], text)) => decode_bytes(text),
+        Some((b"UNICODE\0", text)) => decode_ucs2(text, is_le),
+        Some((b"JIS\0\0\0\0\0", _)) => None,
+        _ => decode_bytes(data),
+    }
+}
@@ -0,0 +1,82 @@
+use std::{
+    collections::HashMap,
+    io::{BufReader, Read, Seek},
+};
+
+use crate::{IFDItem, TiffParser, ToReport};
+use erreport::Report;
+
+/// An IFD found while exploring a file, with the IFDs its pointer tags lead to
+#[derive(Debug)]
+pub struct IFDNode {
+    /// Full path of the IFD, in the same form `gen_tags_info!` and `TagQuery` use
+    pub path: Vec<u16>,
+    /// Every entry of the IFD, sorted by tag
+    pub entries: Vec<IFDItem>,
+    pub children: Vec<IFDNode>,
+}
+
+impl IFDNode {
+    pub fn get(&self, tag: u16) -> Option<&IFDItem> {
+        self.entries.iter().find(|x| x.tag == tag)
+    }
+
+    fn build(path: Vec<u16>, ifds: &mut HashMap<Vec<u16>, Vec<IFDItem>>) -> Self {
+        let mut children_paths = ifds
+            .keys()
+            .filter(|x| x.len() == path.len() + 2 && x.starts_with(&path))
+            .cloned()
+            .collect::<Vec<_>>();
+        children_paths.sort();
+
+        let mut entries = ifds.remove(&path).unwrap_or_default();
+        entries.sort_by_key(|x| x.tag);
+
+        let children = children_paths
+            .into_iter()
+            .map(|x| Self::build(x, ifds))
+            .collect();
+
+        Self {
+            path,
+            entries,
+            children,
+        }
+    }
+}
+
+/// Walks every IFD reachable from the TIFF header without a path list.
+///
+/// Besides the next IFD chains, the ExifIFD, GPS, Interop, SubIFDs, MakerNote and
+/// Sony's DNGPrivateData pointers are followed, and the encrypted SR2SubIFD is decrypted
+/// under `0xc634/0/0x7200/0`. Sub IFDs that fail to parse are skipped,
+/// so unknown makernotes do not break the walk. The returned nodes are IFD0, IFD1, ...
+pub fn parse_exif_tree<T: Read + Seek>(reader: BufReader<T>) -> Result<Vec<IFDNode>, Report> {
+    let mut parser = TiffParser::new(reader, HashMap::new()).to_report()?;
+    parser.explore = true;
+    let collector = parser.parse().to_report()?;
+
+    let mut ifds: HashMap<Vec<u16>, Vec<IFDItem>> = parser
+        .path_map
+        .keys()
+        .map(|path| (path.clone(), vec![]))
+        .collect();
+    let paths: HashMap<u16, &Vec<u16>> = parser.path_map.iter().map(|(k, v)| (*v, k)).collect();
+    for ((path_index, _), item) in collector {
+        if let Some(entries) = paths.get(&path_index).and_then(|x| ifds.get_mut(*x)) {
+            entries.push(item);
+        }
+    }
+
+    let mut roots = ifds
+        .keys()
+        .filter(|x| x.len() == 1)
+        .cloned()
+        .collect::<Vec<_>>();
+    roots.sort();
+
+    Ok(roots
+        .into_iter()
+        .map(|x| IFDNode::build(x, &mut ifds))
+        .collect())
+}
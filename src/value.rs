@@ -0,0 +1,109 @@
+/// The type of an IFD entry, from its format code
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataType {
+    Byte,
+    Ascii,
+    Short,
+    Long,
+    Rational,
+    SByte,
+    Undefined,
+    SShort,
+    SLong,
+    SRational,
+    Float,
+    Double,
+    Ifd,
+    Long8,
+    SLong8,
+    Ifd8,
+    Unknown(u16),
+}
+
+impl DataType {
+    pub fn from_code(code: u16) -> Self {
+        match code {
+            0x0001 => Self::Byte,
+            0x0002 => Self::Ascii,
+            0x0003 => Self::Short,
+            0x0004 => Self::Long,
+            0x0005 => Self::Rational,
+            0x0006 => Self::SByte,
+            0x0007 => Self::Undefined,
+            0x0008 => Self::SShort,
+            0x0009 => Self::SLong,
+            0x000a => Self::SRational,
+            0x000b => Self::Float,
+            0x000c => Self::Double,
+            0x000d => Self::Ifd,
+            0x0010 => Self::Long8,
+            0x0011 => Self::SLong8,
+            0x0012 => Self::Ifd8,
+            x => Self::Unknown(x),
+        }
+    }
+
+    /// Bytes of one element, unknown types are treated as bytes
+    pub fn size(&self) -> u64 {
+        match self {
+            Self::Byte | Self::Ascii | Self::SByte | Self::Undefined | Self::Unknown(_) => 1,
+            Self::Short | Self::SShort => 2,
+            Self::Long | Self::SLong | Self::Float | Self::Ifd => 4,
+            Self::Rational | Self::SRational | Self::Double => 8,
+            Self::Long8 | Self::SLong8 | Self::Ifd8 => 8,
+        }
+    }
+}
+
+macro_rules! gen_rational {
+    ($name:ident, $t:ty) => {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub struct $name {
+            pub num: $t,
+            pub den: $t,
+        }
+
+        impl $name {
+            pub fn new(num: $t, den: $t) -> Self {
+                Self { num, den }
+            }
+            /// `None` when the denominator is zero
+            pub fn to_f64(&self) -> Option<f64> {
+                (self.den != 0).then(|| self.num as f64 / self.den as f64)
+            }
+        }
+
+        /// Keeps the stored form, like `1/8000`
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}/{}", self.num, self.den)
+            }
+        }
+    };
+}
+
+gen_rational!(Rational, u32);
+gen_rational!(SRational, i32);
+
+/// The decoded data of an IFD entry, see `IFDItem::value`
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Byte(Box<[u8]>),
+    /// Everything before the first NUL, invalid UTF-8 is replaced
+    Ascii(String),
+    Short(Box<[u16]>),
+    Long(Box<[u32]>),
+    Rational(Box<[Rational]>),
+    SByte(Box<[i8]>),
+    Undefined(Box<[u8]>),
+    SShort(Box<[i16]>),
+    SLong(Box<[i32]>),
+    SRational(Box<[SRational]>),
+    Float(Box<[f32]>),
+    Double(Box<[f64]>),
+    Ifd(Box<[u32]>),
+    Long8(Box<[u64]>),
+    SLong8(Box<[i64]>),
+    Ifd8(Box<[u64]>),
+    Unknown(u16, Box<[u8]>),
+}